@@ -0,0 +1,32 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// number of `error!` and `warn!` calls, regardless of the active verbosity
+static ERROR_COUNT: AtomicUsize = AtomicUsize::new(0);
+static WARNING_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of errors reported with the `error!` macro
+///
+/// Every call is counted, even if its message is suppressed by the active verbosity, so an
+/// application can exit with a failure code after reporting errors quietly.
+#[must_use]
+pub fn error_count() -> usize {
+    ERROR_COUNT.load(Ordering::Relaxed)
+}
+
+/// Number of warnings reported with the `warn!` macro
+///
+/// Every call is counted, even if its message is suppressed by the active verbosity.
+#[must_use]
+pub fn warning_count() -> usize {
+    WARNING_COUNT.load(Ordering::Relaxed)
+}
+
+/// Counts an error, called by the code generated for the `error!` macro
+pub fn count_error() {
+    ERROR_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Counts a warning, called by the code generated for the `warn!` macro
+pub fn count_warning() {
+    WARNING_COUNT.fetch_add(1, Ordering::Relaxed);
+}
@@ -0,0 +1,43 @@
+use std::env;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+// environment variable of the default output format, `human` or `json`
+const OUTPUT_FORMAT_VAR: &str = "CLI_TOOLBOX_OUTPUT_FORMAT";
+
+// the output format has not been set at runtime, the default output format applies
+const UNSET_OUTPUT_FORMAT: u8 = u8::MAX;
+
+// output format set at runtime, overrides the default output format
+static OUTPUT_FORMAT: AtomicU8 = AtomicU8::new(UNSET_OUTPUT_FORMAT);
+
+// the environment variable is read once, the first time a message is reported
+static DEFAULT_OUTPUT_FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// Format the `report!` macros write their messages in
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// Messages are written as text, the default
+    Human,
+    /// Messages are written as [JSON Lines](https://jsonlines.org) objects
+    Json,
+}
+
+/// Sets the output format, overriding the `CLI_TOOLBOX_OUTPUT_FORMAT` environment variable
+pub fn set_output_format(format: OutputFormat) {
+    OUTPUT_FORMAT.store(format as u8, Ordering::Relaxed);
+}
+
+/// The output format set with [`set_output_format`], otherwise the output format of the
+/// `CLI_TOOLBOX_OUTPUT_FORMAT` environment variable, which defaults to [`OutputFormat::Human`]
+#[must_use]
+pub fn output_format() -> OutputFormat {
+    match OUTPUT_FORMAT.load(Ordering::Relaxed) {
+        format if format == OutputFormat::Human as u8 => OutputFormat::Human,
+        format if format == OutputFormat::Json as u8 => OutputFormat::Json,
+        _ => *DEFAULT_OUTPUT_FORMAT.get_or_init(|| match env::var(OUTPUT_FORMAT_VAR).as_deref() {
+            Ok("json") => OutputFormat::Json,
+            _ => OutputFormat::Human
+        })
+    }
+}
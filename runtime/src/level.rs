@@ -0,0 +1,136 @@
+use std::cell::Cell;
+use std::env;
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+use verbosity::Verbosity;
+
+// environment variable of the verbosity levels of specific modules
+const VERBOSITY_FILTER_VAR: &str = "CLI_VERBOSITY";
+
+thread_local! {
+    // verbosity of the current thread, which overrides the global verbosity while it is set
+    static SCOPED_VERBOSITY: Cell<Option<Verbosity>> = const { Cell::new(None) };
+}
+
+// module paths and their levels, the filter is read once the first time a macro is evaluated
+static VERBOSITY_FILTER: OnceLock<Vec<(String, Verbosity)>> = OnceLock::new();
+
+/// Overrides the verbosity of the current thread until it is dropped, then restores the
+/// verbosity that was overridden before it
+///
+/// Guards can be nested, the most recently created guard applies. A guard is bound to the
+/// thread that created it, so it can not be sent to another thread.
+///
+/// ### Example
+///
+/// ```
+/// use cli_toolbox_runtime::{scoped_verbosity, VerbosityGuard};
+/// use verbosity::Verbosity;
+///
+/// {
+///     let _verbose = VerbosityGuard::new(Verbosity::Verbose);
+///
+///     assert_eq!(Some(Verbosity::Verbose), scoped_verbosity());
+/// }
+///
+/// assert_eq!(None, scoped_verbosity());
+/// ```
+#[must_use = "the verbosity is restored as soon as the guard is dropped"]
+pub struct VerbosityGuard {
+    previous: Option<Verbosity>,
+    // the override belongs to the thread that created the guard
+    thread_bound: PhantomData<*const ()>,
+}
+
+impl VerbosityGuard {
+    /// Overrides the verbosity of the current thread with `level`
+    pub fn new(level: Verbosity) -> Self {
+        let previous = SCOPED_VERBOSITY.with(|scoped| scoped.replace(Some(level)));
+
+        Self { previous, thread_bound: PhantomData }
+    }
+}
+
+impl Drop for VerbosityGuard {
+    fn drop(&mut self) {
+        SCOPED_VERBOSITY.with(|scoped| scoped.set(self.previous));
+    }
+}
+
+/// The verbosity the current thread is overridden with, if it is overridden
+#[must_use]
+pub fn scoped_verbosity() -> Option<Verbosity> {
+    SCOPED_VERBOSITY.with(Cell::get)
+}
+
+/// Evaluates `scope` with the verbosity of the current thread overridden with `level`, the
+/// overridden verbosity is restored afterwards, even if `scope` panics
+pub fn with_verbosity<T>(level: Verbosity, scope: impl FnOnce() -> T) -> T {
+    let _guard = VerbosityGuard::new(level);
+
+    scope()
+}
+
+/// The active verbosity of a macro called in `module`, either the scoped verbosity of the
+/// current thread, the level the verbosity filter assigns to `module` or the global verbosity
+#[must_use]
+pub fn level(module: &str) -> Verbosity {
+    overridden_level(module).unwrap_or_else(Verbosity::level)
+}
+
+/// Evaluates if the active verbosity of a macro called in `module` is quite
+#[must_use]
+pub fn is_quite(module: &str) -> bool {
+    overridden_level(module)
+        .map_or_else(Verbosity::is_quite, |level| matches!(level, Verbosity::Quite))
+}
+
+/// Evaluates if the active verbosity of a macro called in `module` is terse or verbose
+#[must_use]
+pub fn is_terse(module: &str) -> bool {
+    overridden_level(module)
+        .map_or_else(Verbosity::is_terse, |level| !matches!(level, Verbosity::Quite))
+}
+
+/// Evaluates if the active verbosity of a macro called in `module` is verbose
+#[must_use]
+pub fn is_verbose(module: &str) -> bool {
+    overridden_level(module)
+        .map_or_else(Verbosity::is_verbose, |level| matches!(level, Verbosity::Verbose))
+}
+
+// the scoped verbosity or the filtered verbosity of `module`, if either overrides the global
+// verbosity
+fn overridden_level(module: &str) -> Option<Verbosity> {
+    scoped_verbosity().or_else(|| filtered_level(module))
+}
+
+// the most specific module path wins and an entry without a module path applies to every
+// module, i.e. `terse,my_cli::net=verbose`
+fn filtered_level(module: &str) -> Option<Verbosity> {
+    VERBOSITY_FILTER.get_or_init(read_verbosity_filter).iter()
+        .filter(|(path, _)| path.is_empty() || module.strip_prefix(path.as_str())
+            .is_some_and(|sub_module| sub_module.is_empty() || sub_module.starts_with("::")))
+        .max_by_key(|(path, _)| path.len())
+        .map(|(_, level)| *level)
+}
+
+// entries with an unknown level are ignored
+fn read_verbosity_filter() -> Vec<(String, Verbosity)> {
+    let Ok(filters) = env::var(VERBOSITY_FILTER_VAR) else { return Vec::new(); };
+
+    filters.split(',')
+        .filter_map(|filter| {
+            let (path, level) = filter.split_once('=').unwrap_or(("", filter));
+            let level = match level.trim() {
+                "quiet" | "quite" => Verbosity::Quite,
+                "terse" => Verbosity::Terse,
+                "verbose" => Verbosity::Verbose,
+                _ => return None
+            };
+
+            Some((path.trim().to_string(), level))
+        })
+        .collect()
+}
@@ -0,0 +1,159 @@
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+#![deny(clippy::cargo)]
+#![deny(missing_docs)]
+// ==============================================================
+#![allow(clippy::module_name_repetitions)]
+// ==============================================================
+#![doc(html_root_url = "https://docs.rs/cli-toolbox-runtime/0.1.0")]
+
+//! Runtime support for the code generated by the [`cli-toolbox`] macros.
+//!
+//! `cli-toolbox` is a procedural macro crate, which can only export macros. The state the
+//! generated code shares at runtime lives in this crate, so it is a dependency of any crate that
+//! uses the `cli-toolbox` macros.
+//!
+//! ### Scoped Verbosity
+//!
+//! The verbosity of the current thread can be overridden for the duration of a closure with
+//! [`with_verbosity`], or for the lifetime of a [`VerbosityGuard`]. The macros check the
+//! overridden verbosity before the global verbosity, so one job of a thread pool can report
+//! verbosely while the other jobs stay terse.
+//!
+//! ```
+//! use cli_toolbox_runtime::{scoped_verbosity, with_verbosity};
+//! use verbosity::Verbosity;
+//!
+//! with_verbosity(Verbosity::Verbose, || {
+//!     // i.e. reportln! { @verbose "reported regardless of the global verbosity" }
+//!     assert_eq!(Some(Verbosity::Verbose), scoped_verbosity());
+//! });
+//!
+//! assert_eq!(None, scoped_verbosity());
+//! ```
+//!
+//! ### Verbosity Filters
+//!
+//! The macros resolve the active verbosity of the module they are called in with [`level`],
+//! [`is_quite`], [`is_terse`] and [`is_verbose`]. The scoped verbosity applies first, then the
+//! level the `CLI_VERBOSITY` environment variable assigns to the module and finally the global
+//! verbosity.
+//!
+//! _\* the filter is read once, the first time a macro is evaluated_
+//!
+//! ### Output Policy
+//!
+//! The macros write to `io::stdout` and `io::stderr` through [`stdout`] and [`stderr`], and apply
+//! the [`OutputPolicy`] when writing fails with [`apply_output_policy`]. The policy defaults to
+//! the `CLI_TOOLBOX_OUTPUT_POLICY` environment variable and can be set with [`set_output_policy`].
+//! The `try_report!` macros write through [`try_stdout`] and [`try_stderr`] instead, which return
+//! any failure rather than applying the policy.
+//!
+//! ```
+//! use cli_toolbox_runtime::{output_policy, OutputPolicy, set_output_policy};
+//!
+//! set_output_policy(OutputPolicy::Exit);
+//!
+//! assert_eq!(OutputPolicy::Exit, output_policy());
+//! ```
+//!
+//! ### Error and Warning Counts
+//!
+//! The `error!` and `warn!` macros count every call, even when the active verbosity suppresses
+//! their message, so an application can summarize or choose its exit code with [`error_count`]
+//! and [`warning_count`].
+//!
+//! ```
+//! use cli_toolbox_runtime::{error_count, warning_count};
+//!
+//! if error_count() > 0 || warning_count() > 0 {
+//!     eprintln!("finished with {} errors and {} warnings", error_count(), warning_count());
+//! }
+//! ```
+//!
+//! ### Output Format
+//!
+//! The `report!` macros write their messages as text or as JSON Lines according to the
+//! [`OutputFormat`], which defaults to the `CLI_TOOLBOX_OUTPUT_FORMAT` environment variable and
+//! can be set with [`set_output_format`], i.e. for a `--json` command line option.
+//!
+//! ### Styled Output
+//!
+//! The `report!` macros style their messages according to the [`ColorChoice`], which defaults to
+//! the `CLI_TOOLBOX_COLOR`, `CLICOLOR_FORCE` and `NO_COLOR` environment variables and can be set
+//! with [`set_color_choice`], i.e. for a `--color` command line option.
+//!
+//! ```
+//! use cli_toolbox_runtime::{ColorChoice, is_styled, set_color_choice};
+//!
+//! set_color_choice(ColorChoice::Never);
+//!
+//! assert!(!is_styled(|| true));
+//! ```
+//!
+//! ### Progress
+//!
+//! The `progress!` macro creates a [`Progress`], which is drawn live below the output of the
+//! other macros while `io::stdout` is a terminal, or written as plain lines otherwise. The
+//! [`ProgressOutput`] can be set with [`set_progress_output`], i.e. for a `--progress` command
+//! line option. A [`MultiProgress`] tracks concurrent tasks, each with its own [`Progress`] that
+//! can be sent to the thread that runs it.
+//!
+//! An [`OutputGuard`] from [`lock_output`] keeps several messages of the current thread
+//! together, the `report_block!` macro holds one while its lines are reported.
+//!
+//! ```
+//! use cli_toolbox_runtime::{lock_output, ProgressOutput, set_progress_output};
+//!
+//! set_progress_output(ProgressOutput::Lines);
+//!
+//! let _output = lock_output();
+//! ```
+//!
+//! ### Evaluated Values
+//!
+//! The `eval!` and `release!` macros evaluate to an `Option` of the value of the expression
+//! intended for the active verbosity, through [`Evaluated`]. An expression that evaluates to `()`
+//! makes them evaluate to `()`, so they can still be used as statements.
+//!
+//! ```
+//! use cli_toolbox_runtime::{Evaluated, EvaluatedOption as _, EvaluatedUnit as _};
+//!
+//! assert_eq!(Some(42), (&&Evaluated::new(Some(42))).value());
+//!
+//! (&&Evaluated::new(Some(()))).value()
+//! ```
+//!
+//! In debug builds the expressions of `release!` are not compiled, so the macro evaluates to
+//! [`unevaluated`], `None` where an `Option` is expected and `()` otherwise.
+//!
+//! ```
+//! use cli_toolbox_runtime::unevaluated;
+//!
+//! let value: Option<u32> = unevaluated(0);
+//!
+//! assert_eq!(None, value);
+//!
+//! unevaluated(0)
+//! ```
+//!
+//! [`cli-toolbox`]: <https://crates.io/crates/cli-toolbox>
+
+pub use counts::{count_error, count_warning, error_count, warning_count};
+pub use format::{output_format, OutputFormat, set_output_format};
+pub use level::{is_quite, is_terse, is_verbose, level, scoped_verbosity, VerbosityGuard, with_verbosity};
+pub use live::{lock_output, OutputGuard};
+pub use output::{apply_output_policy, Output, output_policy, OutputPolicy, set_output_policy, stderr, stdout, try_stderr, try_stdout};
+pub use progress::{MultiProgress, Progress, progress_output, ProgressOutput, set_progress_output};
+pub use style::{color_choice, ColorChoice, is_styled, set_color_choice};
+pub use value::{Evaluated, EvaluatedOption, EvaluatedUnit, unevaluated, Unevaluated};
+
+mod counts;
+mod format;
+mod level;
+mod live;
+mod output;
+mod progress;
+mod style;
+mod value;
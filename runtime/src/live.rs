@@ -0,0 +1,209 @@
+use std::io::{self, Write};
+use std::marker::PhantomData;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, ThreadId};
+
+use crate::output::{apply_output_policy, print_bytes};
+
+// erases the line of the cursor and moves the cursor to its start
+const ERASE_LINE: &str = "\r\x1b[2K";
+
+// moves the cursor to the line above and erases it
+const ERASE_LINE_ABOVE: &str = "\x1b[1A\x1b[2K";
+
+// thread that holds the output lock and how many times it holds it
+static OUTPUT_OWNER: Mutex<(Option<ThreadId>, usize)> = Mutex::new((None, 0));
+
+static OUTPUT_RELEASED: Condvar = Condvar::new();
+
+// live lines of progress, drawn below any other output of `io::stdout`
+static LIVE_REGION: Mutex<LiveRegion> = Mutex::new(LiveRegion::new());
+
+struct LiveRegion {
+    // identifiers and lines of the region, in the order they are drawn
+    lines: Vec<(usize, String)>,
+    next_id: usize,
+    // number of lines of the region currently drawn on the terminal
+    drawn: usize,
+    // output written above the region did not end its line, the region is drawn again once it does
+    partial_line: bool,
+}
+
+impl LiveRegion {
+    const fn new() -> Self {
+        Self { lines: Vec::new(), next_id: 0, drawn: 0, partial_line: false }
+    }
+
+    fn clear(&mut self) {
+        let erase = self.erase();
+
+        write_region(&erase);
+    }
+
+    fn draw(&mut self) {
+        let lines = self.lines();
+
+        write_region(&lines);
+    }
+
+    // erases the region, writes `above` and draws the region below it with a single write, so the
+    // terminal never shows a partially drawn region
+    fn redraw(&mut self, above: &str) {
+        let erase = self.erase();
+        let lines = self.lines();
+
+        write_region(&format!("{erase}{above}{lines}"));
+    }
+
+    fn erase(&mut self) -> String {
+        if self.drawn == 0 {
+            return String::new();
+        }
+
+        let erase = format!("{ERASE_LINE}{}", ERASE_LINE_ABOVE.repeat(self.drawn - 1));
+
+        self.drawn = 0;
+
+        erase
+    }
+
+    fn lines(&mut self) -> String {
+        if self.partial_line {
+            return String::new();
+        }
+
+        self.drawn = self.lines.len();
+
+        self.lines.iter().map(|(_, line)| line.as_str()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Holds the output of the macros for the current thread until it is dropped
+///
+/// Output of other threads waits for the guard to be dropped, so a block of messages is not
+/// interleaved with other messages or with redraws of live progress. The current thread can
+/// continue to report while it holds the guard.
+#[must_use = "the output is released as soon as the guard is dropped"]
+pub struct OutputGuard {
+    // the lock belongs to the thread that acquired it
+    thread_bound: PhantomData<*const ()>,
+}
+
+impl Drop for OutputGuard {
+    fn drop(&mut self) {
+        let mut owner = OUTPUT_OWNER.lock().unwrap_or_else(PoisonError::into_inner);
+
+        owner.1 -= 1;
+
+        if owner.1 == 0 {
+            owner.0 = None;
+
+            drop(owner);
+
+            OUTPUT_RELEASED.notify_one();
+        }
+    }
+}
+
+/// Holds the output of the macros for the current thread, see [`OutputGuard`]
+pub fn lock_output() -> OutputGuard {
+    let current = thread::current().id();
+    let mut owner = OUTPUT_OWNER.lock().unwrap_or_else(PoisonError::into_inner);
+
+    while owner.0.is_some_and(|thread| thread != current) {
+        owner = OUTPUT_RELEASED.wait(owner).unwrap_or_else(PoisonError::into_inner);
+    }
+
+    owner.0 = Some(current);
+    owner.1 += 1;
+
+    drop(owner);
+
+    OutputGuard { thread_bound: PhantomData }
+}
+
+// writes output above the live region, the region is cleared first and drawn again afterwards
+pub fn write_above(ends_line: bool, write: impl FnOnce() -> io::Result<()>) -> io::Result<()> {
+    let _output = lock_output();
+    let mut region = live_region();
+
+    if region.lines.is_empty() {
+        return write();
+    }
+
+    region.clear();
+
+    let result = write();
+
+    region.partial_line = !ends_line;
+    region.draw();
+
+    result
+}
+
+// adds a line to the live region, above the line `before` or otherwise at the bottom
+pub fn insert_line(line: String, before: Option<usize>) -> usize {
+    let _output = lock_output();
+    let mut region = live_region();
+    let id = region.next_id;
+    let index = before
+        .and_then(|before| region.lines.iter().position(|(line_id, _)| *line_id == before))
+        .unwrap_or(region.lines.len());
+
+    region.next_id += 1;
+    region.lines.insert(index, (id, line));
+    region.redraw("");
+
+    id
+}
+
+// replaces a line of the live region
+pub fn update_line(id: usize, line: String) {
+    let _output = lock_output();
+    let mut region = live_region();
+
+    if let Some((_, current)) = region.lines.iter_mut().find(|(line_id, _)| *line_id == id) {
+        *current = line;
+    }
+
+    region.redraw("");
+}
+
+// removes a line from the live region, its last state is written above the region to stay visible
+pub fn remove_line(id: usize, last: Option<&str>) {
+    let _output = lock_output();
+    let mut region = live_region();
+
+    region.lines.retain(|(line_id, _)| *line_id != id);
+
+    let above = match last {
+        Some(last) if region.partial_line => format!("\n{last}\n"),
+        Some(last) => format!("{last}\n"),
+        None => String::new()
+    };
+
+    if last.is_some() {
+        region.partial_line = false;
+    }
+
+    region.redraw(&above);
+}
+
+// evaluates if progress is drawn live
+pub fn is_live() -> bool {
+    !live_region().lines.is_empty()
+}
+
+fn live_region() -> MutexGuard<'static, LiveRegion> {
+    LIVE_REGION.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn write_region(output: &str) {
+    if output.is_empty() {
+        return;
+    }
+
+    let result = print_bytes(false, output.as_bytes()).and_then(|()| io::stdout().flush());
+
+    apply_output_policy(result, false);
+}
@@ -0,0 +1,178 @@
+use std::env;
+use std::fmt;
+use std::io::{self, ErrorKind, Write};
+use std::process;
+use std::str;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+use crate::live;
+
+// environment variable of the default output policy, `exit`, `ignore` or `panic`
+const OUTPUT_POLICY_VAR: &str = "CLI_TOOLBOX_OUTPUT_POLICY";
+
+// conventional exit code of a process terminated by `SIGPIPE`, i.e. 128 + 13
+const BROKEN_PIPE_EXIT_CODE: i32 = 141;
+
+// the output policy has not been set at runtime, the default output policy applies
+const UNSET_OUTPUT_POLICY: u8 = u8::MAX;
+
+// output policy set at runtime, overrides the default output policy
+static OUTPUT_POLICY: AtomicU8 = AtomicU8::new(UNSET_OUTPUT_POLICY);
+
+// the environment variable is read once, the first time the output policy is consulted
+static DEFAULT_OUTPUT_POLICY: OnceLock<OutputPolicy> = OnceLock::new();
+
+/// What the macros do when a reader of `io::stdout` or `io::stderr` closes its end of a pipe,
+/// i.e. `my-cli | head`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutputPolicy {
+    /// Panics, like [`println!`], the default
+    Panic,
+    /// Exits the process quietly with the conventional `SIGPIPE` exit code, `141`
+    Exit,
+    /// Ignores the closed pipe and continues execution
+    Ignore,
+}
+
+/// Sets the output policy, overriding the `CLI_TOOLBOX_OUTPUT_POLICY` environment variable
+pub fn set_output_policy(policy: OutputPolicy) {
+    OUTPUT_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// The output policy set with [`set_output_policy`], otherwise the policy of the
+/// `CLI_TOOLBOX_OUTPUT_POLICY` environment variable, which defaults to [`OutputPolicy::Panic`]
+#[must_use]
+pub fn output_policy() -> OutputPolicy {
+    match OUTPUT_POLICY.load(Ordering::Relaxed) {
+        policy if policy == OutputPolicy::Panic as u8 => OutputPolicy::Panic,
+        policy if policy == OutputPolicy::Exit as u8 => OutputPolicy::Exit,
+        policy if policy == OutputPolicy::Ignore as u8 => OutputPolicy::Ignore,
+        _ => *DEFAULT_OUTPUT_POLICY.get_or_init(|| match env::var(OUTPUT_POLICY_VAR).as_deref() {
+            Ok("exit") => OutputPolicy::Exit,
+            Ok("ignore") => OutputPolicy::Ignore,
+            _ => OutputPolicy::Panic
+        })
+    }
+}
+
+/// Writer of the `debug!` and `report!` macros to `io::stdout` or `io::stderr`
+///
+/// With the [`OutputPolicy::Panic`] policy, text is written with [`print!`] or [`eprint!`], so
+/// it is captured by the test harness like any other printed output. Raw bytes that are not
+/// valid UTF-8, or any output with another policy, are written to the locked stream.
+///
+/// The writers of the `try_report!` macros, [`try_stdout`] and [`try_stderr`], always write to
+/// the locked stream, so any failure is returned to the caller.
+///
+/// Output is written above live progress, which is drawn again once the output ends its line.
+pub struct Output {
+    std_err: bool,
+    fallible: bool,
+}
+
+/// Writer to `io::stdout`, see [`Output`]
+#[must_use]
+pub const fn stdout() -> Output {
+    Output { std_err: false, fallible: false }
+}
+
+/// Writer to `io::stderr`, see [`Output`]
+#[must_use]
+pub const fn stderr() -> Output {
+    Output { std_err: true, fallible: false }
+}
+
+/// Fallible writer to `io::stdout`, see [`Output`]
+#[must_use]
+pub const fn try_stdout() -> Output {
+    Output { std_err: false, fallible: true }
+}
+
+/// Fallible writer to `io::stderr`, see [`Output`]
+#[must_use]
+pub const fn try_stderr() -> Output {
+    Output { std_err: true, fallible: true }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.std_err { io::stderr().flush() } else { io::stdout().flush() }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        live::write_above(buf.ends_with(b"\n"), || if self.fallible {
+            write_locked(self.std_err, |stream| stream.write_all(buf))
+        } else {
+            print_bytes(self.std_err, buf)
+        })
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> io::Result<()> {
+        let _output = live::lock_output();
+
+        // whether live progress is drawn again depends on the end of the formatted output
+        if live::is_live() {
+            return self.write_all(args.to_string().as_bytes());
+        }
+
+        if self.fallible {
+            return write_locked(self.std_err, |stream| stream.write_fmt(args));
+        }
+
+        print_args(self.std_err, args)
+    }
+}
+
+// writes raw bytes, text is printed when the output policy panics
+pub fn print_bytes(std_err: bool, buf: &[u8]) -> io::Result<()> {
+    str::from_utf8(buf).map_or_else(
+        |_| write_locked(std_err, |stream| stream.write_all(buf)),
+        |text| print_args(std_err, format_args!("{text}")),
+    )
+}
+
+fn print_args(std_err: bool, args: fmt::Arguments<'_>) -> io::Result<()> {
+    if output_policy() != OutputPolicy::Panic {
+        return write_locked(std_err, |stream| stream.write_fmt(args));
+    }
+
+    if std_err {
+        eprint!("{args}");
+    } else {
+        print!("{args}");
+    }
+
+    Ok(())
+}
+
+fn write_locked(std_err: bool, write: impl FnOnce(&mut dyn Write) -> io::Result<()>) -> io::Result<()> {
+    if std_err {
+        write(&mut io::stderr().lock())
+    } else {
+        write(&mut io::stdout().lock())
+    }
+}
+
+/// Applies the output policy to the result of writing to `io::stdout` or `io::stderr`, any
+/// failure other than a closed pipe always panics
+///
+/// # Panics
+///
+/// If writing failed and the output policy does not handle the failure
+pub fn apply_output_policy(result: io::Result<()>, std_err: bool) {
+    let Err(err) = result else { return; };
+
+    match output_policy() {
+        OutputPolicy::Exit if err.kind() == ErrorKind::BrokenPipe =>
+            process::exit(BROKEN_PIPE_EXIT_CODE),
+        OutputPolicy::Ignore if err.kind() == ErrorKind::BrokenPipe => {}
+        _ => panic!("failed printing to {}: {err}", if std_err { "stderr" } else { "stdout" })
+    }
+}
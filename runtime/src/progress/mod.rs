@@ -0,0 +1,396 @@
+use std::fmt::Write as _;
+use std::io::{self, IsTerminal, Write as _};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, Instant};
+
+use verbosity::Verbosity;
+
+use crate::{live, output_format, OutputFormat};
+use crate::output::apply_output_policy;
+
+pub use multi::MultiProgress;
+
+mod multi;
+
+// live progress is redrawn at most this often
+const LIVE_INTERVAL: Duration = Duration::from_millis(100);
+
+// progress written as plain lines is written at most this often
+const LINES_INTERVAL: Duration = Duration::from_secs(1);
+
+const SPINNER: [char; 4] = ['-', '\\', '|', '/'];
+
+const BAR_WIDTH: u64 = 30;
+
+// how progress is written, set at runtime
+static PROGRESS_OUTPUT: AtomicU8 = AtomicU8::new(ProgressOutput::Auto as u8);
+
+/// How the [`Progress`] of the `progress!` macro is written to `io::stdout`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgressOutput {
+    /// Progress is drawn live when `io::stdout` is a terminal, otherwise it is written as plain
+    /// lines, the default
+    Auto,
+    /// Progress is drawn live, below any other output
+    Live,
+    /// Progress is written as plain lines, at most once per second
+    Lines,
+    /// Progress is not written
+    Hidden,
+}
+
+/// Sets how progress is written, i.e. for a `--progress` command line option
+pub fn set_progress_output(output: ProgressOutput) {
+    PROGRESS_OUTPUT.store(output as u8, Ordering::Relaxed);
+}
+
+/// How progress is written, set with [`set_progress_output`], defaults to [`ProgressOutput::Auto`]
+#[must_use]
+pub fn progress_output() -> ProgressOutput {
+    match PROGRESS_OUTPUT.load(Ordering::Relaxed) {
+        output if output == ProgressOutput::Live as u8 => ProgressOutput::Live,
+        output if output == ProgressOutput::Lines as u8 => ProgressOutput::Lines,
+        output if output == ProgressOutput::Hidden as u8 => ProgressOutput::Hidden,
+        _ => ProgressOutput::Auto
+    }
+}
+
+/// Progress of a task, created by the `progress!` macro
+///
+/// Progress is drawn according to the verbosity it was created with, it is hidden at quite, a
+/// spinner at terse and a bar with the rate and the estimated time remaining at verbose, if the
+/// total is known. When `io::stdout` is not a terminal, progress is written as plain lines
+/// instead, see [`ProgressOutput`].
+///
+/// Messages reported while progress is drawn live are written above it. Clones share the same
+/// progress, so a task can be advanced from several threads. Progress that is dropped before it
+/// is finished is erased.
+///
+/// ### Example
+///
+/// ```
+/// use cli_toolbox_runtime::Progress;
+/// use verbosity::Verbosity;
+///
+/// let progress = Progress::new(Verbosity::Quite, Some(3), String::from("copying"));
+///
+/// for _ in 0..3 {
+///     progress.inc(1);
+/// }
+///
+/// progress.finish();
+///
+/// assert_eq!(3, progress.position());
+/// ```
+#[derive(Clone)]
+pub struct Progress {
+    state: Arc<Mutex<State>>,
+}
+
+struct State {
+    level: Verbosity,
+    total: Option<u64>,
+    position: u64,
+    message: String,
+    mode: Mode,
+    started: Instant,
+    // last time the progress was written, `None` until it is written
+    written: Option<Instant>,
+    frame: usize,
+    finished: bool,
+    // multi-progress the progress is a task of
+    multi: Option<MultiProgress>,
+}
+
+enum Mode {
+    Hidden,
+    // identifier of the line of the live region
+    Live(usize),
+    Lines,
+}
+
+impl Mode {
+    // hidden at quite, drawn live above the line `before` when `io::stdout` is a terminal,
+    // otherwise written as plain lines
+    fn new(level: Verbosity, before: Option<usize>, line: impl FnOnce() -> String) -> Self {
+        let output = progress_output();
+        let hidden = level == Verbosity::Quite ||
+            output == ProgressOutput::Hidden ||
+            output_format() == OutputFormat::Json;
+        let drawn_live = output == ProgressOutput::Live ||
+            (output == ProgressOutput::Auto && io::stdout().is_terminal());
+
+        if hidden {
+            Self::Hidden
+        } else if drawn_live {
+            Self::Live(live::insert_line(line(), before))
+        } else {
+            Self::Lines
+        }
+    }
+
+    // progress is written again once its interval has passed since it was last written
+    fn is_due(&self, written: Option<Instant>) -> bool {
+        let interval = match self {
+            Self::Hidden => return false,
+            Self::Live(_) => LIVE_INTERVAL,
+            Self::Lines => LINES_INTERVAL
+        };
+
+        !matches!(written, Some(written) if written.elapsed() < interval)
+    }
+}
+
+impl Progress {
+    /// Creates the progress of a task towards an optional total, drawn according to `level`
+    #[must_use]
+    pub fn new(level: Verbosity, total: Option<u64>, message: String) -> Self {
+        let mut state = State::new(level, total, message, None);
+
+        state.mode = Mode::new(level, None, || state.render_live());
+
+        Self::from_state(state)
+    }
+
+    fn from_state(mut state: State) -> Self {
+        if let Mode::Live(_) = state.mode {
+            state.written = Some(Instant::now());
+        }
+
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+
+    /// Advances the position by `delta`
+    pub fn inc(&self, delta: u64) {
+        self.update(|state| state.position = state.position.saturating_add(delta));
+    }
+
+    /// Sets the position
+    pub fn set_position(&self, position: u64) {
+        self.update(|state| state.position = position);
+    }
+
+    /// Replaces the message
+    pub fn set_message(&self, message: String) {
+        self.update(|state| state.message = message);
+    }
+
+    /// The current position
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        self.lock().position
+    }
+
+    /// Finishes the progress, its final state stays visible unless it is hidden
+    pub fn finish(&self) {
+        let _output = live::lock_output();
+        let mut state = self.lock();
+
+        if state.finished {
+            return;
+        }
+
+        state.finished = true;
+
+        let finished = state.render_finished();
+
+        match state.mode {
+            Mode::Hidden => {}
+            Mode::Live(id) => live::remove_line(id, Some(&finished)),
+            Mode::Lines => write_line(&finished)
+        }
+
+        if let Some(multi) = &state.multi {
+            multi.finish_task();
+        }
+    }
+
+    /// Replaces the message and finishes the progress
+    pub fn finish_with_message(&self, message: String) {
+        self.lock().message = message;
+        self.finish();
+    }
+
+    // changes the state, the progress is only written again once its interval has passed, so a
+    // change that is not due does not wait for the output of other threads
+    fn update(&self, change: impl FnOnce(&mut State)) {
+        let mut state = self.lock();
+
+        change(&mut state);
+
+        let due = state.is_due();
+        let multi = state.multi.clone();
+
+        drop(state);
+
+        if due {
+            let _output = live::lock_output();
+
+            self.lock().write();
+        }
+
+        // a task that is not drawn on its own still shows activity on the overall line
+        if let Some(multi) = multi {
+            multi.tick();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl State {
+    fn new(
+        level: Verbosity, total: Option<u64>, message: String, multi: Option<MultiProgress>,
+    ) -> Self {
+        Self {
+            level,
+            total,
+            position: 0,
+            message,
+            mode: Mode::Hidden,
+            started: Instant::now(),
+            written: None,
+            frame: 0,
+            finished: false,
+            multi,
+        }
+    }
+
+    fn is_due(&self) -> bool {
+        !self.finished && self.mode.is_due(self.written)
+    }
+
+    fn write(&mut self) {
+        if !self.is_due() {
+            return;
+        }
+
+        self.written = Some(Instant::now());
+
+        match self.mode {
+            Mode::Hidden => {}
+            Mode::Live(id) => {
+                self.frame = (self.frame + 1) % SPINNER.len();
+
+                live::update_line(id, self.render_live());
+            }
+            Mode::Lines => {
+                write_line(&self.render_line());
+            }
+        }
+    }
+
+    // spinner at terse, bar with rate and estimated time remaining at verbose
+    fn render_live(&self) -> String {
+        let frame = SPINNER[self.frame];
+
+        match (self.level, self.total) {
+            (Verbosity::Verbose, Some(total)) => format!(
+                "{} [{}] {}/{total} {} eta {}",
+                self.message, render_bar(self.position, total), self.position,
+                self.render_rate(), format_duration(self.eta(total))
+            ),
+            (Verbosity::Verbose, None) =>
+                format!("{frame} {}{} {}", self.message, self.render_counts(), self.render_rate()),
+            _ =>
+                format!("{frame} {}{}", self.message, self.render_counts())
+        }
+    }
+
+    fn render_line(&self) -> String {
+        let mut line = format!("{}{}", self.message, self.render_counts());
+
+        if self.level == Verbosity::Verbose {
+            let _ = write!(line, " {}", self.render_rate());
+        }
+
+        line
+    }
+
+    fn render_finished(&self) -> String {
+        let mut line = format!("{}{} done", self.message, self.render_counts());
+
+        if self.level == Verbosity::Verbose {
+            let _ = write!(line, " in {}", format_duration(self.started.elapsed()));
+        }
+
+        line
+    }
+
+    fn render_counts(&self) -> String {
+        match self.total {
+            Some(total) => format!(" {}/{total}", self.position),
+            None if self.position > 0 => format!(" {}", self.position),
+            None => String::new()
+        }
+    }
+
+    fn render_rate(&self) -> String {
+        format!("{:.1}/s", self.rate())
+    }
+
+    // positions per second since the progress was created
+    #[allow(clippy::cast_precision_loss)]
+    fn rate(&self) -> f64 {
+        let elapsed = self.started.elapsed().as_secs_f64();
+
+        if elapsed > 0.0 { self.position as f64 / elapsed } else { 0.0 }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn eta(&self, total: u64) -> Duration {
+        let rate = self.rate();
+        let remaining = total.saturating_sub(self.position) as f64;
+
+        if rate > 0.0 { Duration::from_secs_f64(remaining / rate) } else { Duration::ZERO }
+    }
+}
+
+impl Drop for State {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        if let Mode::Live(id) = self.mode {
+            live::remove_line(id, None);
+        }
+
+        if let Some(multi) = &self.multi {
+            multi.remove_task();
+        }
+    }
+}
+
+fn write_line(line: &str) {
+    apply_output_policy(writeln!(crate::stdout(), "{line}"), false);
+}
+
+fn render_bar(position: u64, total: u64) -> String {
+    // widened so the product can not overflow, the quotient never exceeds the width of the bar
+    let filled = (u128::from(position.min(total)) * u128::from(BAR_WIDTH))
+        .checked_div(u128::from(total))
+        .map_or(BAR_WIDTH, |filled| u64::try_from(filled).unwrap_or(BAR_WIDTH));
+
+    if filled == BAR_WIDTH {
+        return "=".repeat(usize::try_from(BAR_WIDTH).unwrap_or_default());
+    }
+
+    let filled = usize::try_from(filled).unwrap_or_default();
+    let empty = usize::try_from(BAR_WIDTH).unwrap_or_default() - filled - 1;
+
+    format!("{}>{}", "=".repeat(filled), " ".repeat(empty))
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+
+    if secs < 60 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    }
+}
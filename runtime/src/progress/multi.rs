@@ -0,0 +1,225 @@
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+use verbosity::Verbosity;
+
+use crate::live;
+use crate::progress::{format_duration, Mode, Progress, render_bar, SPINNER, State, write_line};
+
+/// Progress of concurrent tasks, created by the `progress!` macro with `@multi`
+///
+/// Every task has its own [`Progress`], which can be sent to the thread that runs it. At verbose,
+/// every task is drawn on its own line above an overall line, at terse the tasks collapse into
+/// the overall line, which summarizes how many tasks are done, and at quite nothing is drawn.
+/// When `io::stdout` is not a terminal, progress is written as plain lines instead, see
+/// [`ProgressOutput`](crate::ProgressOutput).
+///
+/// The lines of the tasks and the overall line are redrawn with a single write, messages reported
+/// in the meantime are written above them.
+///
+/// ### Example
+///
+/// ```
+/// use std::thread;
+///
+/// use cli_toolbox_runtime::MultiProgress;
+/// use verbosity::Verbosity;
+///
+/// let multi = MultiProgress::new(Verbosity::Quite, String::from("downloading"));
+///
+/// thread::scope(|scope| {
+///     for file in ["a.txt", "b.txt"] {
+///         let task = multi.add(Some(1), file.to_string());
+///
+///         scope.spawn(move || {
+///             task.inc(1);
+///             task.finish();
+///         });
+///     }
+/// });
+///
+/// multi.finish();
+/// ```
+#[derive(Clone)]
+pub struct MultiProgress {
+    state: Arc<Mutex<MultiState>>,
+}
+
+struct MultiState {
+    level: Verbosity,
+    message: String,
+    // mode of the overall line
+    mode: Mode,
+    started: Instant,
+    written: Option<Instant>,
+    frame: usize,
+    tasks: usize,
+    done: usize,
+    finished: bool,
+}
+
+impl MultiProgress {
+    /// Creates the progress of concurrent tasks, drawn according to `level`
+    #[must_use]
+    pub fn new(level: Verbosity, message: String) -> Self {
+        let mut state = MultiState {
+            level,
+            message,
+            mode: Mode::Hidden,
+            started: Instant::now(),
+            written: None,
+            frame: 0,
+            tasks: 0,
+            done: 0,
+            finished: false,
+        };
+
+        state.mode = Mode::new(level, None, || state.render_live());
+
+        if let Mode::Live(_) = state.mode {
+            state.written = Some(Instant::now());
+        }
+
+        Self { state: Arc::new(Mutex::new(state)) }
+    }
+
+    /// Adds a task towards an optional total, its progress can be sent to another thread
+    #[must_use]
+    pub fn add(&self, total: Option<u64>, message: String) -> Progress {
+        let _output = live::lock_output();
+        let mut state = self.lock();
+        let mut task = State::new(state.level, total, message, Some(self.clone()));
+
+        // tasks are only drawn on their own at verbose, otherwise the overall line summarizes them
+        task.mode = match state.mode {
+            Mode::Hidden => Mode::Hidden,
+            _ if state.level != Verbosity::Verbose => Mode::Hidden,
+            Mode::Live(overall) => Mode::Live(live::insert_line(task.render_live(), Some(overall))),
+            Mode::Lines => Mode::Lines
+        };
+
+        state.tasks += 1;
+        state.redraw();
+
+        drop(state);
+
+        Progress::from_state(task)
+    }
+
+    /// Finishes the progress of all tasks, the overall line stays visible unless it is hidden
+    pub fn finish(&self) {
+        let _output = live::lock_output();
+        let mut state = self.lock();
+
+        if state.finished {
+            return;
+        }
+
+        state.finished = true;
+
+        let finished = state.render_finished();
+
+        match state.mode {
+            Mode::Hidden => {}
+            Mode::Live(id) => live::remove_line(id, Some(&finished)),
+            Mode::Lines => write_line(&finished)
+        }
+    }
+
+    // a task is done, the overall line is drawn again right away
+    pub(super) fn finish_task(&self) {
+        let mut state = self.lock();
+
+        state.done += 1;
+        state.redraw();
+    }
+
+    // a task was dropped before it was finished, it no longer counts towards the overall line
+    pub(super) fn remove_task(&self) {
+        let _output = live::lock_output();
+        let mut state = self.lock();
+
+        state.tasks -= 1;
+        state.redraw();
+    }
+
+    // a task advanced, the overall line is written again once its interval has passed
+    pub(super) fn tick(&self) {
+        if !self.lock().is_due() {
+            return;
+        }
+
+        let _output = live::lock_output();
+        let mut state = self.lock();
+
+        if state.is_due() {
+            state.frame = (state.frame + 1) % SPINNER.len();
+            state.write();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MultiState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl MultiState {
+    fn is_due(&self) -> bool {
+        !self.finished && self.mode.is_due(self.written)
+    }
+
+    // live progress shows every change of the tasks, plain lines are only written when due
+    fn redraw(&mut self) {
+        if let Mode::Live(_) = self.mode {
+            if !self.finished {
+                self.write();
+            }
+        } else if self.is_due() {
+            self.write();
+        }
+    }
+
+    fn write(&mut self) {
+        self.written = Some(Instant::now());
+
+        match self.mode {
+            Mode::Hidden => {}
+            Mode::Live(id) => live::update_line(id, self.render_live()),
+            Mode::Lines => write_line(&format!("{}{}", self.message, self.render_counts()))
+        }
+    }
+
+    // spinner at terse, bar of the tasks that are done at verbose once there are tasks
+    fn render_live(&self) -> String {
+        if self.level == Verbosity::Verbose && self.tasks > 0 {
+            let bar = render_bar(self.done as u64, self.tasks as u64);
+
+            return format!("{} [{bar}]{}", self.message, self.render_counts());
+        }
+
+        format!("{} {}{}", SPINNER[self.frame], self.message, self.render_counts())
+    }
+
+    fn render_finished(&self) -> String {
+        let mut line = format!("{}{} done", self.message, self.render_counts());
+
+        if self.level == Verbosity::Verbose {
+            let _ = write!(line, " in {}", format_duration(self.started.elapsed()));
+        }
+
+        line
+    }
+
+    fn render_counts(&self) -> String {
+        if self.tasks == 0 { String::new() } else { format!(" {}/{}", self.done, self.tasks) }
+    }
+}
+
+impl Drop for MultiState {
+    fn drop(&mut self) {
+        if let (Mode::Live(id), false) = (&self.mode, self.finished) {
+            live::remove_line(*id, None);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::env;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+// environment variable of the default color choice, `always` or `never`
+const COLOR_VAR: &str = "CLI_TOOLBOX_COLOR";
+
+// the color choice has not been set at runtime, the default color choice applies
+const UNSET_COLOR_CHOICE: u8 = u8::MAX;
+
+// color choice set at runtime, overrides the default color choice
+static COLOR_CHOICE: AtomicU8 = AtomicU8::new(UNSET_COLOR_CHOICE);
+
+// the environment variables are read once, the first time a styled message is reported
+static DEFAULT_COLOR_CHOICE: OnceLock<ColorChoice> = OnceLock::new();
+
+/// When the `report!` macros apply the styles of their messages
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ColorChoice {
+    /// Styles are applied when the output is a terminal
+    Auto,
+    /// Styles are always applied
+    Always,
+    /// Styles are never applied
+    Never,
+}
+
+/// Sets the color choice, overriding the `CLI_TOOLBOX_COLOR`, `CLICOLOR_FORCE` and `NO_COLOR`
+/// environment variables
+pub fn set_color_choice(choice: ColorChoice) {
+    COLOR_CHOICE.store(choice as u8, Ordering::Relaxed);
+}
+
+/// The color choice set with [`set_color_choice`], otherwise the color choice of the environment
+///
+/// The `CLI_TOOLBOX_COLOR` environment variable set to `always` or `never`, then a
+/// `CLICOLOR_FORCE` environment variable that is not `0` and then a `NO_COLOR` environment
+/// variable that is not empty, in that order, determine the color choice of the environment,
+/// which defaults to [`ColorChoice::Auto`]
+#[must_use]
+pub fn color_choice() -> ColorChoice {
+    match COLOR_CHOICE.load(Ordering::Relaxed) {
+        choice if choice == ColorChoice::Auto as u8 => ColorChoice::Auto,
+        choice if choice == ColorChoice::Always as u8 => ColorChoice::Always,
+        choice if choice == ColorChoice::Never as u8 => ColorChoice::Never,
+        _ => *DEFAULT_COLOR_CHOICE.get_or_init(|| match env::var(COLOR_VAR).as_deref() {
+            Ok("always") => ColorChoice::Always,
+            Ok("never") => ColorChoice::Never,
+            _ if env::var("CLICOLOR_FORCE").is_ok_and(|force| !force.is_empty() && force != "0") =>
+                ColorChoice::Always,
+            _ if env::var_os("NO_COLOR").is_some_and(|no_color| !no_color.is_empty()) =>
+                ColorChoice::Never,
+            _ => ColorChoice::Auto
+        })
+    }
+}
+
+/// Evaluates if styles are applied to output, `is_terminal` is only consulted when the color
+/// choice is [`ColorChoice::Auto`]
+#[must_use]
+pub fn is_styled(is_terminal: impl FnOnce() -> bool) -> bool {
+    match color_choice() {
+        ColorChoice::Auto => is_terminal(),
+        ColorChoice::Always => true,
+        ColorChoice::Never => false
+    }
+}
@@ -0,0 +1,70 @@
+use std::cell::Cell;
+
+/// Value the code generated for the `eval!` and `release!` macros evaluates to
+///
+/// The macros evaluate to an `Option` of the value of the expression intended for the active
+/// verbosity, which is `None` when nothing was evaluated. Expressions that evaluate to `()`, i.e.
+/// assignments or calls for their side effects, make the macros evaluate to `()` instead, so the
+/// macros can still be used as statements.
+///
+/// The generated code calls `value` on `&&Evaluated`, [`EvaluatedUnit`] applies to `&Evaluated<()>`
+/// before [`EvaluatedOption`] applies to any other `Evaluated` after a dereference.
+pub struct Evaluated<T>(Cell<Option<T>>);
+
+impl<T> Evaluated<T> {
+    /// Wraps the value of the expression intended for the active verbosity, if any
+    #[must_use]
+    pub const fn new(value: Option<T>) -> Self {
+        Self(Cell::new(value))
+    }
+}
+
+/// Discards the value of an expression that evaluates to `()`, see [`Evaluated`]
+pub trait EvaluatedUnit {
+    /// Evaluates to `()`
+    fn value(&self);
+}
+
+impl EvaluatedUnit for &Evaluated<()> {
+    fn value(&self) {
+        self.0.take();
+    }
+}
+
+/// Takes the value of an expression as an `Option`, see [`Evaluated`]
+pub trait EvaluatedOption<T> {
+    /// Evaluates to the value, or `None` when nothing was evaluated
+    fn value(&self) -> Option<T>;
+}
+
+impl<T> EvaluatedOption<T> for Evaluated<T> {
+    fn value(&self) -> Option<T> {
+        self.0.take()
+    }
+}
+
+/// Value of the `release!` macro in debug builds, where its expressions are not compiled, see
+/// [`unevaluated`]
+pub trait Unevaluated<T> {
+    /// Evaluates to `None` or `()`
+    fn unevaluated(self) -> T;
+}
+
+impl Unevaluated<()> for i32 {
+    fn unevaluated(self) {}
+}
+
+impl<T> Unevaluated<Option<T>> for u8 {
+    fn unevaluated(self) -> Option<T> {
+        None
+    }
+}
+
+/// Evaluates to `None` where an `Option` is expected, or to `()` otherwise
+///
+/// The type of the value can not be known from expressions that are not compiled, so it is
+/// inferred from where the macro is used. The generated code calls `unevaluated(0)`, the integer
+/// literal is a `u8` when an `Option` is expected, otherwise it falls back to an `i32`.
+pub fn unevaluated<S: Unevaluated<T>, T>(selector: S) -> T {
+    selector.unevaluated()
+}
@@ -54,3 +54,7 @@ echo -e "${YELLOW}running clippy report optimized"
 cargo clippy --release --features="report"
 confirm-success "clippy report optimized"
 
+
+echo -e "${YELLOW}running clippy runtime"
+cargo clippy -p cli-toolbox-runtime
+confirm-success "clippy runtime"
@@ -28,4 +28,7 @@ confirm-success "test all macros unoptimized"
 
 echo -e "${YELLOW}running test all macros optimized"
 cargo test --features="all" --release -- --nocapture --test-threads=1
-confirm-success "test all macros optimized"
\ No newline at end of file
+confirm-success "test all macros optimized"
+echo -e "${YELLOW}running test runtime"
+cargo test -p cli-toolbox-runtime
+confirm-success "test runtime"
@@ -52,3 +52,7 @@ Write-Host "running clippy report optimized" -ForegroundColor Yellow
 cargo clippy --release --features="report"
 Confirm-Success "clippy report optimized"
 
+
+Write-Host "running clippy runtime" -ForegroundColor Yellow
+cargo clippy -p cli-toolbox-runtime
+Confirm-Success "clippy runtime"
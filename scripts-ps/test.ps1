@@ -27,3 +27,7 @@ Confirm-Success "test unoptimized"
 Write-Host "running test all macros optimized" -ForegroundColor Yellow
 cargo test --features="all" --release -- --nocapture --test-threads=1
 Confirm-Success "test optimized"
+
+Write-Host "running test runtime" -ForegroundColor Yellow
+cargo test -p cli-toolbox-runtime
+Confirm-Success "test runtime"
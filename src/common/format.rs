@@ -0,0 +1,430 @@
+use std::ops::Range;
+
+use proc_macro2::Span;
+use syn::{Error, Expr, ExprAssign, ExprPath, Ident, LitByteStr, LitStr};
+use syn::spanned::Spanned;
+
+const ARG_NAME_ERR: &str = "invalid format string: invalid argument name `_`";
+
+const BYTE_NAMED_ARG_ERR: &str = "byte string messages do not support named arguments";
+
+const BYTE_PLACEHOLDER_ERR: &str =
+    "invalid format string: byte string messages only support `{}` placeholders";
+
+const ARG_ORDER_ERR: &str = "positional arguments cannot follow named arguments";
+
+const TERMINATED_ERR: &str = "invalid format string: expected `}` but string was terminated";
+
+const UNMATCHED_ERR: &str = "invalid format string: unmatched `}` found";
+
+// reference of a placeholder, or of its width or precision, to a message argument
+enum Reference {
+    Implicit,
+    Index(usize),
+    Name(String),
+}
+
+// reference of a placeholder and the range of the placeholder in the format string
+struct Placeholder {
+    range: Range<usize>,
+    reference: Reference,
+}
+
+// validates the placeholders of a format string against the arguments of a message the same
+// way `format_args!` does, so errors point at the format string rather than the macro call
+pub fn validate_format(fmt: &LitStr, args: &[Expr], name: Option<&str>) -> syn::Result<()> {
+    let describe = |message: String| match name {
+        Some(name) => format!("{name} message: {message}"),
+        None => message
+    };
+    let value = fmt.value();
+    let placeholders = parse_placeholders(&value)
+        .map_err(|(range, message)| Error::new(subspan(fmt, &value, range), describe(message)))?;
+
+    let mut named = Vec::new();
+    let mut errors = Vec::new();
+
+    for arg in args {
+        match named_arg(arg) {
+            Some(ident) => {
+                if named.iter().any(|(named, _)| named == &ident) {
+                    let message = format!("duplicate argument named `{ident}`");
+
+                    errors.push(Error::new(ident.span(), describe(message)));
+                }
+
+                named.push((ident, arg));
+            }
+            None if !named.is_empty() =>
+                errors.push(Error::new(arg.span(), describe(ARG_ORDER_ERR.to_string()))),
+            None => {}
+        }
+    }
+
+    // arguments can only be resolved when positional arguments precede named arguments
+    if let Some(errors) = combine(errors) {
+        return Err(errors);
+    }
+
+    let positional = args.len() - named.len();
+    let mut errors = Vec::new();
+    let mut used = vec![false; args.len()];
+    let mut next = 0;
+    let mut missing = None;
+
+    for placeholder in &placeholders {
+        let index = match &placeholder.reference {
+            Reference::Implicit => {
+                next += 1;
+
+                if next > args.len() {
+                    missing.get_or_insert_with(|| placeholder.range.clone());
+                }
+
+                next - 1
+            }
+            Reference::Index(index) => {
+                if *index >= args.len() {
+                    errors.push(Error::new(
+                        subspan(fmt, &value, placeholder.range.clone()),
+                        describe(format!(
+                            "invalid reference to positional argument {index} in `{}` ({})",
+                            &value[placeholder.range.clone()], describe_args(args.len())
+                        )),
+                    ));
+                }
+
+                *index
+            }
+            // names that are not arguments are captured from the scope of the macro call
+            Reference::Name(name) => match named.iter().position(|(named, _)| named == name) {
+                Some(index) => positional + index,
+                None => continue
+            }
+        };
+
+        if let Some(used) = used.get_mut(index) {
+            *used = true;
+        }
+    }
+
+    if let Some(range) = missing {
+        let plural = if next == 1 { "" } else { "s" };
+        let message = format!(
+            "{next} positional argument{plural} in format string, but {}, missing in `{}`",
+            describe_args(args.len()), &value[range.clone()]
+        );
+
+        errors.push(Error::new(subspan(fmt, &value, range), describe(message)));
+    }
+
+    for (index, arg) in args.iter().enumerate().filter(|(index, _)| !used[*index]) {
+        let message = if index < positional {
+            "argument never used"
+        } else {
+            "named argument never used"
+        };
+
+        errors.push(Error::new(arg.span(), describe(message.to_string())));
+    }
+
+    combine(errors).map_or(Ok(()), Err)
+}
+
+// validates the `{}` placeholders of a byte string format against the arguments of a message,
+// which are written as raw bytes rather than formatted
+pub fn validate_byte_format(fmt: &LitByteStr, args: &[Expr], name: Option<&str>) -> syn::Result<()> {
+    let describe = |message: String| match name {
+        Some(name) => format!("{name} message: {message}"),
+        None => message
+    };
+    let pieces = split_byte_format(&fmt.value())
+        .map_err(|message| Error::new(fmt.span(), describe(message)))?;
+    let placeholders = pieces.len() - 1;
+    let mut errors = Vec::new();
+
+    for ident in args.iter().filter_map(named_arg) {
+        errors.push(Error::new(ident.span(), describe(BYTE_NAMED_ARG_ERR.to_string())));
+    }
+
+    if placeholders > args.len() {
+        let plural = if placeholders == 1 { "" } else { "s" };
+        let message = format!(
+            "{placeholders} positional argument{plural} in format string, but {}",
+            describe_args(args.len())
+        );
+
+        errors.push(Error::new(fmt.span(), describe(message)));
+    }
+
+    // named arguments are already reported as unsupported
+    for arg in args.iter().skip(placeholders).filter(|arg| named_arg(arg).is_none()) {
+        errors.push(Error::new(arg.span(), describe("argument never used".to_string())));
+    }
+
+    combine(errors).map_or(Ok(()), Err)
+}
+
+// splits a byte string format into the literal pieces around its `{}` placeholders,
+// unescaping `{{` and `}}`
+pub fn split_byte_format(value: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    let mut pieces = Vec::new();
+    let mut piece = Vec::new();
+    let mut position = 0;
+
+    while let Some(byte) = value.get(position) {
+        match (byte, value.get(position + 1)) {
+            (b'{', Some(b'{')) | (b'}', Some(b'}')) => {
+                piece.push(*byte);
+                position += 2;
+            }
+            (b'{', Some(b'}')) => {
+                pieces.push(std::mem::take(&mut piece));
+                position += 2;
+            }
+            (b'{', _) => {
+                // the literal is the span of the error, so the message tells which placeholder
+                let end = value[position..].iter().position(|byte| *byte == b'}')
+                    .map_or(value.len(), |end| position + end + 1);
+                let placeholder = String::from_utf8_lossy(&value[position..end]);
+
+                return Err(format!("{BYTE_PLACEHOLDER_ERR} in `{placeholder}`"));
+            }
+            (b'}', _) => return Err(UNMATCHED_ERR.to_string()),
+            _ => {
+                piece.push(*byte);
+                position += 1;
+            }
+        }
+    }
+
+    pieces.push(piece);
+
+    Ok(pieces)
+}
+
+fn combine(errors: Vec<Error>) -> Option<Error> {
+    errors.into_iter().reduce(|mut errors, error| {
+        errors.combine(error);
+        errors
+    })
+}
+
+fn describe_args(count: usize) -> String {
+    match count {
+        0 => "no arguments were given".to_string(),
+        1 => "there is 1 argument".to_string(),
+        count => format!("there are {count} arguments")
+    }
+}
+
+// the name of a named argument, i.e. `name = expr`
+fn named_arg(arg: &Expr) -> Option<Ident> {
+    match arg {
+        Expr::Assign(ExprAssign { left, .. }) => match left.as_ref() {
+            Expr::Path(ExprPath { path, qself: None, .. }) => path.get_ident().cloned(),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+// the span of a range of the format string, which can only be narrowed by nightly compilers
+// and only if the literal does not contain escapes, otherwise it is the span of the literal
+fn subspan(fmt: &LitStr, value: &str, range: Range<usize>) -> Span {
+    let source = fmt.token().to_string();
+    let hashes = source.strip_prefix('r')
+        .map_or(0, |raw| raw.len() - raw.trim_start_matches('#').len());
+    let prefix = if hashes > 0 || source.starts_with("r\"") { hashes + 2 } else { 1 };
+
+    source.get(prefix..source.len() - hashes - 1)
+        .filter(|content| *content == value)
+        .and_then(|_| fmt.token().subspan(range.start + prefix..range.end + prefix))
+        .unwrap_or_else(|| fmt.span())
+}
+
+// parses the placeholders of a format string, i.e. `{argument:[[fill]align][sign]['#']['0']
+// [width]['.' precision]type}`, returning the range and reason of the first invalid one
+fn parse_placeholders(value: &str) -> Result<Vec<Placeholder>, (Range<usize>, String)> {
+    let chars = value.char_indices().collect::<Vec<_>>();
+    let end = value.len();
+    let offset = |position: usize| chars.get(position).map_or(end, |(offset, _)| *offset);
+    let char_at = |position: usize| chars.get(position).map(|(_, chr)| *chr);
+    // subspans of a placeholder cover the whole literal on stable compilers, so the message
+    // tells which placeholder is invalid
+    let in_placeholder = |range: Range<usize>, message: &str| {
+        let placeholder = format!("{message} in `{}`", &value[range.clone()]);
+
+        (range, placeholder)
+    };
+    let mut placeholders = Vec::new();
+    let mut position = 0;
+
+    while let Some(chr) = char_at(position) {
+        match chr {
+            '{' if char_at(position + 1) == Some('{') => position += 2,
+            '}' if char_at(position + 1) == Some('}') => position += 2,
+            '}' =>
+                return Err((offset(position)..offset(position + 1), UNMATCHED_ERR.to_string())),
+            '{' => {
+                let start = position;
+                let mut references = Vec::new();
+
+                position += 1;
+
+                let argument = parse_argument(&chars, &mut position);
+
+                if let Some(Reference::Name(name)) = &argument {
+                    if name == "_" {
+                        return Err(in_placeholder(offset(start)..offset(position), ARG_NAME_ERR));
+                    }
+                }
+
+                skip_whitespace(&chars, &mut position);
+
+                if char_at(position) == Some(':') {
+                    position += 1;
+
+                    parse_spec(&chars, &mut position, &mut references).map_err(|message| {
+                        in_placeholder(offset(start)..offset(position + 1), &message)
+                    })?;
+
+                    skip_whitespace(&chars, &mut position);
+                }
+
+                match char_at(position) {
+                    Some('}') => {}
+                    Some(chr) => return Err(in_placeholder(
+                        offset(start)..offset(position + 1),
+                        &format!("invalid format string: expected `}}`, found `{chr}`")
+                    )),
+                    None =>
+                        return Err(in_placeholder(offset(start)..end, TERMINATED_ERR))
+                }
+
+                position += 1;
+
+                // a precision of `.*` takes the next implicit argument before the value does
+                references.push(argument.unwrap_or(Reference::Implicit));
+
+                let range = offset(start)..offset(position);
+
+                placeholders.extend(
+                    references.into_iter().map(|reference| Placeholder { range: range.clone(), reference })
+                );
+            }
+            _ => position += 1
+        }
+    }
+
+    Ok(placeholders)
+}
+
+fn parse_argument(chars: &[(usize, char)], position: &mut usize) -> Option<Reference> {
+    match chars.get(*position).map(|(_, chr)| *chr) {
+        Some(chr) if chr.is_ascii_digit() => {
+            let digits = take_while(chars, position, |chr| chr.is_ascii_digit());
+
+            // an index too large to parse is also too large to reference an argument
+            Some(Reference::Index(digits.parse().unwrap_or(usize::MAX)))
+        }
+        Some(chr) if chr == '_' || chr.is_alphabetic() =>
+            Some(Reference::Name(take_while(chars, position, is_identifier))),
+        _ => None
+    }
+}
+
+fn parse_spec(
+    chars: &[(usize, char)], position: &mut usize, references: &mut Vec<Reference>,
+) -> Result<(), String> {
+    let char_at = |position: usize| chars.get(position).map(|(_, chr)| *chr);
+    let is_align = |chr: Option<char>| matches!(chr, Some('<' | '^' | '>'));
+
+    // fill and align
+    if is_align(char_at(*position + 1)) {
+        *position += 2;
+    } else if is_align(char_at(*position)) {
+        *position += 1;
+    }
+
+    // sign and alternate flags
+    if matches!(char_at(*position), Some('+' | '-')) { *position += 1; }
+    if char_at(*position) == Some('#') { *position += 1; }
+
+    // zero padding flag, unless it is the argument of the width
+    if char_at(*position) == Some('0') && char_at(*position + 1) != Some('$') { *position += 1; }
+
+    if let Some(reference) = parse_count(chars, position) {
+        references.push(reference);
+    }
+
+    if char_at(*position) == Some('.') {
+        *position += 1;
+
+        if char_at(*position) == Some('*') {
+            *position += 1;
+
+            references.push(Reference::Implicit);
+        } else if let Some(reference) = parse_count(chars, position) {
+            references.push(reference);
+        }
+    }
+
+    match char_at(*position) {
+        Some('?') => *position += 1,
+        Some('x' | 'X') if char_at(*position + 1) == Some('?') => *position += 2,
+        Some(chr) if chr == '_' || chr.is_alphabetic() => {
+            let format_trait = take_while(chars, position, is_identifier);
+
+            if !matches!(format_trait.as_str(), "b" | "e" | "E" | "o" | "p" | "x" | "X") {
+                return Err(format!("unknown format trait `{format_trait}`"));
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+// a width or precision, which only references an argument if it is followed by `$`
+fn parse_count(chars: &[(usize, char)], position: &mut usize) -> Option<Reference> {
+    let start = *position;
+    let reference = parse_argument(chars, position)?;
+
+    match (chars.get(*position).map(|(_, chr)| *chr), reference) {
+        (Some('$'), reference) => {
+            *position += 1;
+
+            Some(reference)
+        }
+        // a literal count
+        (_, Reference::Index(_) | Reference::Implicit) => None,
+        // not a count but the format trait
+        (_, Reference::Name(_)) => {
+            *position = start;
+
+            None
+        }
+    }
+}
+
+fn skip_whitespace(chars: &[(usize, char)], position: &mut usize) {
+    take_while(chars, position, char::is_whitespace);
+}
+
+fn is_identifier(chr: char) -> bool {
+    chr == '_' || chr.is_alphanumeric()
+}
+
+fn take_while(
+    chars: &[(usize, char)], position: &mut usize, predicate: impl Fn(char) -> bool,
+) -> String {
+    let mut taken = String::new();
+
+    while let Some((_, chr)) = chars.get(*position).filter(|(_, chr)| predicate(*chr)) {
+        taken.push(*chr);
+        *position += 1;
+    }
+
+    taken
+}
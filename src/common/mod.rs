@@ -3,53 +3,168 @@ use std::fmt::{Display, Formatter};
 
 #[cfg(any(feature = "debug", feature = "report"))]
 use proc_macro2::TokenStream;
-#[cfg(all(debug_assertions, feature = "trace"))]
+#[cfg(any(feature = "debug", feature = "report"))]
+use proc_macro2::{Ident, Span};
+#[cfg(any(feature = "debug", feature = "report"))]
 use quote::ToTokens;
 #[cfg(any(feature = "debug", feature = "report"))]
-use syn::{Expr, Lit};
+use syn::{Expr, Lit, LitByteStr, Macro};
 
 #[cfg(all(debug_assertions, feature = "trace"))]
 use crate::displays;
+#[cfg(any(feature = "debug", feature = "report"))]
+use crate::common::format::split_byte_format;
 
+#[cfg(any(feature = "debug", feature = "report"))]
+pub mod format;
 pub mod parse;
 pub mod tokenize;
 pub mod tracing;
 
 #[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
 pub mod kw {
+    #[cfg(feature = "report")]
+    custom_keyword!(always);
+    #[cfg(any(feature = "debug", feature = "report"))]
+    custom_keyword!(args);
     #[cfg(any(feature = "debug", feature = "report"))]
     custom_keyword!(err);
     #[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
     custom_keyword!(terse);
+    #[cfg(any(feature = "eval", feature = "release", feature = "report"))]
+    custom_keyword!(level);
+    #[cfg(feature = "debug")]
+    custom_keyword!(loc);
+    #[cfg(feature = "report")]
+    custom_keyword!(multi);
+    #[cfg(any(feature = "eval", feature = "release", feature = "report"))]
+    custom_keyword!(quiet);
+    #[cfg(any(feature = "eval", feature = "release", feature = "report"))]
+    custom_keyword!(quite);
+    #[cfg(feature = "report")]
+    custom_keyword!(to);
+    #[cfg(feature = "report")]
+    custom_keyword!(total);
     #[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
     custom_keyword!(verbose);
 }
 
-#[cfg(any(feature = "eval", feature = "release", feature = "report"))]
+#[cfg(any(feature = "eval", feature = "release"))]
 pub const QUITE_ERR: &str = "quite should not be able to be specified";
 
 #[cfg(any(feature = "eval", feature = "release", feature = "report"))]
 pub const DUPE_VERBOSITY_ERR: &str = "do not duplicate verbosity";
 
+#[cfg(any(feature = "eval", feature = "release", feature = "report"))]
+pub const QUITE_ORDER_ERR: &str = "define quiet before terse and verbose";
+
 #[cfg(any(feature = "eval", feature = "release", feature = "report"))]
 pub const VERBOSITY_ORDER_ERR: &str = "define terse before verbose";
 
+#[cfg(feature = "report")]
+const STYLE_RESET: &str = "\x1b[0m";
+
+// version of the json lines output schema, incremented whenever its fields change
+#[cfg(feature = "report")]
+const JSON_SCHEMA_VERSION: u8 = 1;
+
+// macros that expand to a string literal, which can be used as a format string
+#[cfg(any(feature = "debug", feature = "report"))]
+pub const FORMAT_MACROS: [&str; 3] = ["concat", "include_str", "stringify"];
+
+// format string of a message, a literal or a macro that expands to one, i.e. `concat!`
+#[cfg(any(feature = "debug", feature = "report"))]
+pub enum Format {
+    Lit(Lit),
+    Macro(Macro),
+}
+
+#[cfg(any(feature = "debug", feature = "report"))]
+impl ToTokens for Format {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Lit(lit) => lit.to_tokens(tokens),
+            Self::Macro(mac) => mac.to_tokens(tokens)
+        }
+    }
+}
+
 #[cfg(any(feature = "debug", feature = "report"))]
 pub struct Message {
     pub args: Option<Vec<Expr>>,
-    pub fmt: Lit,
+    pub fmt: Format,
     pub ln_brk: bool,
+    // prefixes the message with the source location of the call
+    #[cfg(feature = "debug")]
+    pub location: bool,
+    #[cfg(feature = "report")]
+    pub record: Option<Record>,
+    #[cfg(feature = "report")]
+    pub style: Option<Style>,
+}
+
+// fields of a message in json lines output, known when the macro is expanded
+#[cfg(feature = "report")]
+pub struct Record {
+    pub level: &'static str,
+    pub stream: &'static str,
+}
+
+// select graphic rendition parameters of an ansi escape sequence
+#[cfg(feature = "report")]
+pub struct Style {
+    pub codes: Vec<u8>,
+}
+
+#[cfg(feature = "report")]
+impl Style {
+    fn escape(&self) -> String {
+        let codes = self.codes.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        format!("\x1b[{}m", codes.join(";"))
+    }
 }
 
 #[cfg(any(feature = "debug", feature = "report"))]
 impl Message {
+    // writes to `io::stdout` or `io::stderr` through the runtime, which prints like `println!`
+    // and applies the output policy when writing fails
     pub(crate) fn build_message(&self, std_err: bool) -> TokenStream {
-        let report = if std_err {
-            if self.ln_brk { quote! { eprintln! } } else { quote! { eprint! } }
-        } else if self.ln_brk { quote! { println! } } else {
-            quote! { print! }
-        };
-        let fmt = &self.fmt;
+        let stream = if std_err { quote! { stderr } } else { quote! { stdout } };
+        let write = self.build_write_expr(&quote! { cli_toolbox_runtime::#stream() }, &is_terminal(std_err));
+
+        quote! {
+            {
+                use std::io::Write as _;
+
+                cli_toolbox_runtime::apply_output_policy(#write, #std_err);
+            }
+        }
+    }
+
+    #[cfg(feature = "report")]
+    pub(crate) fn build_write<W: ToTokens>(
+        &self, writer: &W, is_terminal: &TokenStream, fallible: bool,
+    ) -> TokenStream {
+        let write = self.build_write_expr(writer, is_terminal);
+        let write = if fallible { write } else { quote! { #write.expect("failed writing report output") } };
+        // the output stays locked while writing, so a writer to a terminal is not interleaved
+        // with redraws of live progress, mixed site hygiene keeps the lock from clashing with
+        // identifiers in the message
+        let output = Ident::new("_output", Span::mixed_site());
+
+        quote! {
+            {
+                use std::io::Write as _;
+
+                let #output = cli_toolbox_runtime::lock_output();
+
+                #write
+            }
+        }
+    }
+
+    fn build_args(&self) -> TokenStream {
         let mut args = TokenStream::new();
 
         if let Some(message_args) = &self.args {
@@ -58,10 +173,251 @@ impl Message {
             }
         }
 
-        quote! { #report(#fmt #args) }
+        args
+    }
+
+    // evaluates to the message as a `String`, the raw bytes of a byte string message are
+    // converted lossily
+    #[cfg(feature = "report")]
+    pub(crate) fn build_formatted(&self) -> TokenStream {
+        match &self.fmt {
+            Format::Lit(Lit::ByteStr(fmt)) => {
+                let bytes = self.build_bytes_expr(fmt);
+
+                quote! { String::from_utf8_lossy(&#bytes).into_owned() }
+            }
+            fmt => {
+                let args = self.build_args();
+
+                quote! { format!(#fmt #args) }
+            }
+        }
+    }
+
+    // evaluates to the `io::Result` of writing the message, as a json record when the output
+    // format is json, the format is read once by the report macro before any of its messages
+    fn build_write_expr<W: ToTokens>(&self, writer: &W, is_terminal: &TokenStream) -> TokenStream {
+        let text = self.build_text_expr(writer, is_terminal);
+
+        #[cfg(feature = "report")]
+        if let Some(record) = &self.record {
+            let json = self.build_json_expr(writer, record);
+            let json_format = json_format_ident();
+
+            return quote! { if #json_format { #json } else { #text } };
+        }
+
+        text
+    }
+
+    // writes one json object per line, regardless of the message's line break
+    #[cfg(feature = "report")]
+    fn build_json_expr<W: ToTokens>(&self, writer: &W, record: &Record) -> TokenStream {
+        let formatted = self.build_formatted();
+        let json = format!(
+            r#"{{{{"version":{JSON_SCHEMA_VERSION},"level":"{}","stream":"{}","message":"{{}}","module":"{{}}"}}}}"#,
+            record.level, record.stream
+        );
+        // mixed site hygiene keeps these from being captured by the message format
+        let message = Ident::new("message", Span::mixed_site());
+        let escaped = Ident::new("escaped", Span::mixed_site());
+        let chr = Ident::new("chr", Span::mixed_site());
+
+        quote! {
+            {
+                let #message = #formatted;
+                let mut #escaped = String::with_capacity(#message.len());
+
+                for #chr in #message.chars() {
+                    match #chr {
+                        '"' => #escaped.push_str("\\\""),
+                        '\\' => #escaped.push_str("\\\\"),
+                        '\n' => #escaped.push_str("\\n"),
+                        '\r' => #escaped.push_str("\\r"),
+                        '\t' => #escaped.push_str("\\t"),
+                        #chr if #chr.is_control() => #escaped.push_str(&format!("\\u{:04x}", u32::from(#chr))),
+                        #chr => #escaped.push(#chr)
+                    }
+                }
+
+                writeln!(#writer, #json, #escaped, module_path!())
+            }
+        }
+    }
+
+    // evaluates to the `io::Result` of writing the message, styled or located if it is meant to be
+    #[cfg_attr(not(feature = "report"), allow(unused_variables))]
+    fn build_text_expr<W: ToTokens>(&self, writer: &W, is_terminal: &TokenStream) -> TokenStream {
+        if let Format::Lit(Lit::ByteStr(fmt)) = &self.fmt {
+            return self.build_bytes_text_expr(writer, is_terminal, fmt);
+        }
+
+        let report = if self.ln_brk { quote! { writeln! } } else { quote! { write! } };
+        let fmt = &self.fmt;
+        let args = self.build_args();
+
+        #[cfg(feature = "report")]
+        if let Some(style) = &self.style {
+            let escape = style.escape();
+            let styled = build_styled_check(is_terminal);
+            // mixed site hygiene keeps these from being captured by the message format
+            let style = Ident::new("style", Span::mixed_site());
+            let reset = Ident::new("reset", Span::mixed_site());
+
+            return quote! {
+                {
+                    let (#style, #reset) = if #styled { (#escape, #STYLE_RESET) } else { ("", "") };
+
+                    #report(#writer, "{}{}{}", #style, format_args!(#fmt #args), #reset)
+                }
+            };
+        }
+
+        #[cfg(feature = "debug")]
+        if self.location {
+            return quote! {
+                #report(#writer, "[{}:{} {}] {}", file!(), line!(), module_path!(), format_args!(#fmt #args))
+            };
+        }
+
+        quote! { #report(#writer, #fmt #args) }
+    }
+
+    // evaluates to the `io::Result` of writing the raw bytes of a byte string message, with a
+    // single `write_all` so styles, locations and line breaks are not interleaved with other output
+    #[cfg_attr(not(feature = "report"), allow(unused_variables))]
+    fn build_bytes_text_expr<W: ToTokens>(
+        &self, writer: &W, is_terminal: &TokenStream, fmt: &LitByteStr,
+    ) -> TokenStream {
+        let bytes = self.build_bytes_expr(fmt);
+        // mixed site hygiene keeps these from being captured by the message arguments
+        let message = Ident::new("message", Span::mixed_site());
+        let mut parts = vec![quote! { #message.as_slice() }];
+        #[cfg_attr(not(feature = "report"), allow(unused_mut))]
+        let mut escapes = TokenStream::new();
+
+        #[cfg(feature = "report")]
+        if let Some(style) = &self.style {
+            let escape = style.escape();
+            let styled = build_styled_check(is_terminal);
+            let style = Ident::new("style", Span::mixed_site());
+            let reset = Ident::new("reset", Span::mixed_site());
+
+            escapes = quote! {
+                let (#style, #reset): (&[u8], &[u8]) =
+                    if #styled { (#escape.as_bytes(), #STYLE_RESET.as_bytes()) } else { (&[], &[]) };
+            };
+            parts.insert(0, quote! { #style });
+            parts.push(quote! { #reset });
+        }
+
+        #[cfg(feature = "debug")]
+        if self.location {
+            parts.insert(0, quote! { format!("[{}:{} {}] ", file!(), line!(), module_path!()).as_bytes() });
+        }
+
+        if self.ln_brk {
+            parts.push(quote! { b"\n".as_slice() });
+        }
+
+        quote! {
+            {
+                let #message: Vec<u8> = #bytes;
+                #escapes
+
+                (#writer).write_all(&[#(#parts),*].concat())
+            }
+        }
+    }
+
+    // evaluates to the raw bytes of a byte string message, its arguments are inserted as they are,
+    // i.e. `[u8]`, `str`, `OsStr`, `Path` or anything that dereferences to one of them
+    fn build_bytes_expr(&self, fmt: &LitByteStr) -> TokenStream {
+        // mixed site hygiene keeps these from being captured by the message arguments
+        let bytes = Ident::new("bytes", Span::mixed_site());
+        let as_bytes = Ident::new("MessageBytes", Span::mixed_site());
+        let args = self.args.as_deref().unwrap_or_default();
+        // the placeholders of byte string formats are validated when the message is parsed
+        let pieces = split_byte_format(&fmt.value()).unwrap_or_else(|_| vec![fmt.value()]);
+        let mut extend = TokenStream::new();
+
+        for (index, piece) in pieces.iter().enumerate() {
+            if !piece.is_empty() {
+                let piece = LitByteStr::new(piece, fmt.span());
+
+                extend.extend(quote! { #bytes.extend_from_slice(#piece); });
+            }
+
+            if let Some(arg) = args.get(index) {
+                extend.extend(quote! { #bytes.extend_from_slice((#arg).message_bytes()); });
+            }
+        }
+
+        let conversions = if args.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! {
+                trait #as_bytes { fn message_bytes(&self) -> &[u8]; }
+
+                impl #as_bytes for [u8] {
+                    fn message_bytes(&self) -> &[u8] { self }
+                }
+
+                impl #as_bytes for str {
+                    fn message_bytes(&self) -> &[u8] { self.as_bytes() }
+                }
+
+                impl #as_bytes for std::ffi::OsStr {
+                    fn message_bytes(&self) -> &[u8] { self.as_encoded_bytes() }
+                }
+
+                impl #as_bytes for std::path::Path {
+                    fn message_bytes(&self) -> &[u8] { self.as_os_str().as_encoded_bytes() }
+                }
+            }
+        };
+
+        quote! {
+            {
+                #conversions
+
+                let mut #bytes = Vec::new();
+
+                #extend
+
+                #bytes
+            }
+        }
     }
 }
 
+// evaluates if `io::stdout` or `io::stderr` is a terminal
+#[cfg(any(feature = "debug", feature = "report"))]
+pub fn is_terminal(std_err: bool) -> TokenStream {
+    let stream = if std_err { quote! { stderr } } else { quote! { stdout } };
+
+    quote! { std::io::IsTerminal::is_terminal(&std::io::#stream()) }
+}
+
+// evaluates if the output format of the runtime is json, once per call of a report macro
+#[cfg(feature = "report")]
+pub fn build_json_format_check() -> TokenStream {
+    quote! { cli_toolbox_runtime::output_format() == cli_toolbox_runtime::OutputFormat::Json }
+}
+
+// mixed site hygiene keeps the output format from clashing with identifiers in the macro input
+#[cfg(feature = "report")]
+pub fn json_format_ident() -> Ident {
+    Ident::new("json_format", Span::mixed_site())
+}
+
+// evaluates if styled output should be used according to the color choice of the runtime, which
+// only consults whether the output is a terminal when it is not overridden
+#[cfg(feature = "report")]
+fn build_styled_check(is_terminal: &TokenStream) -> TokenStream {
+    quote! { cli_toolbox_runtime::is_styled(|| #is_terminal) }
+}
+
 #[cfg(all(debug_assertions, any(feature = "debug", feature = "report"), feature = "trace"))]
 impl Display for Message {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
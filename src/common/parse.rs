@@ -1,30 +1,87 @@
 use syn::{Error, Expr};
 #[cfg(any(feature = "debug", feature = "report"))]
-use syn::Lit;
+use syn::{Lit, LitStr, Macro};
+#[cfg(feature = "debug")]
+use syn::LitByteStr;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
 #[cfg(any(feature = "eval", feature = "release"))]
 use verbosity::Verbosity;
 
 #[cfg(any(feature = "eval", feature = "release"))]
-use crate::common::{DUPE_VERBOSITY_ERR, QUITE_ERR, VERBOSITY_ORDER_ERR};
-#[cfg(any(feature = "eval", feature = "release"))]
+use crate::common::{DUPE_VERBOSITY_ERR, QUITE_ERR, QUITE_ORDER_ERR, VERBOSITY_ORDER_ERR};
+#[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
 use crate::common::kw;
 #[cfg(any(feature = "debug", feature = "report"))]
-use crate::common::Message;
+use crate::common::{Format, FORMAT_MACROS, Message};
+#[cfg(any(feature = "debug", feature = "report"))]
+use crate::common::format::{validate_byte_format, validate_format};
+
+#[cfg(any(feature = "debug", feature = "report"))]
+const FORMAT_ERR: &str =
+    "expecting a string literal, or a concat!, stringify! or include_str! format string";
 
 #[cfg(any(feature = "debug", feature = "report"))]
 impl Message {
     pub fn parse(input: ParseStream, ln_brk: bool) -> syn::Result<Self> {
-        Ok(Self {
-            fmt: parse_format(input)?,
-            args: parse_args(input)?,
+        let message = Self::parse_unvalidated(input, ln_brk)?;
+
+        message.validate(None)?;
+
+        Ok(message)
+    }
+
+    // parses a message without validating its format string, for a macro that only knows whether
+    // to name the message once it is parsed
+    pub fn parse_unvalidated(input: ParseStream, ln_brk: bool) -> syn::Result<Self> {
+        // prebuilt arguments, i.e. `args: format_args!(..)`, are displayed as they are
+        if peek_arguments(input) {
+            <kw::args>::parse(input)?;
+            <Token![:]>::parse(input)?;
+
+            let arguments = <Expr>::parse(input)?;
+            let fmt = Format::Lit(Lit::Str(LitStr::new("{}", arguments.span())));
+
+            parse_optional_semicolon(input, true)?;
+
+            return Ok(Self::new(fmt, Some(vec![arguments]), ln_brk));
+        }
+
+        let fmt = parse_format(input)?;
+        let args = parse_args(input)?;
+
+        Ok(Self::new(fmt, args, ln_brk))
+    }
+
+    pub(crate) const fn new(fmt: Format, args: Option<Vec<Expr>>, ln_brk: bool) -> Self {
+        Self {
+            fmt,
+            args,
             ln_brk,
-        })
+            #[cfg(feature = "debug")]
+            location: false,
+            #[cfg(feature = "report")]
+            record: None,
+            #[cfg(feature = "report")]
+            style: None,
+        }
+    }
+
+    // the format string of a macro is only known once the macro is expanded, the name of a
+    // message, i.e. `terse`, tells which clause of a macro with several clauses an invalid format
+    // string belongs to; byte strings are written as raw bytes and only support `{}` placeholders
+    pub fn validate(&self, name: Option<&str>) -> syn::Result<()> {
+        let args = self.args.as_deref().unwrap_or_default();
+
+        match &self.fmt {
+            Format::Lit(Lit::Str(fmt)) => validate_format(fmt, args, name),
+            Format::Lit(Lit::ByteStr(fmt)) => validate_byte_format(fmt, args, name),
+            _ => Ok(())
+        }
     }
 }
 
-#[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
+#[cfg(any(feature = "debug", feature = "eval", feature = "release"))]
 pub fn decode_expr_type(expr: &Expr) -> &'static str {
     match expr {
         Expr::Array(_) => "array",
@@ -74,8 +131,15 @@ pub fn decode_expr_type(expr: &Expr) -> &'static str {
 #[cfg(any(feature = "eval", feature = "release"))]
 #[allow(clippy::shadow_unrelated)] // intention of code is clear
 pub fn parse_expr_eval<T>(
-    input: ParseStream, macro_name: &str, builder: impl Fn(Option<Expr>, Option<Expr>) -> T,
+    input: ParseStream, macro_name: &str,
+    builder: impl FnOnce(Option<Expr>, Option<Expr>, Option<Expr>) -> T,
 ) -> syn::Result<T> {
+    if input.peek(Token![@]) && input.peek3(Token![=>]) {
+        let (quite, terse, verbose) = parse_expr_arms(input)?;
+
+        return Ok(builder(Some(quite), Some(terse), Some(verbose)));
+    }
+
     let verbosity = parse_verbosity(input, false)?;
     let expr = parse_expression(input, macro_name)?;
     let error_span = input.span();
@@ -84,10 +148,8 @@ pub fn parse_expr_eval<T>(
         Some(Verbosity::Quite) =>
             unreachable!("{}", QUITE_ERR),
         Some(Verbosity::Terse) | None => {
-            let verbose = if let Ok(Some(verbose)) = parse_verbosity(input, true) {
-                verbose
-            } else {
-                return Ok(builder(Some(expr), None));
+            let Ok(Some(verbose)) = parse_verbosity(input, true) else {
+                return Ok(builder(None, Some(expr), None));
             };
 
             match verbose {
@@ -97,12 +159,12 @@ pub fn parse_expr_eval<T>(
                     Err(Error::new(error_span, DUPE_VERBOSITY_ERR)),
                 Verbosity::Verbose =>
                 // only accept a second expression that is intended for verbose output
-                    Ok(builder(Some(expr), Some(parse_expression(input, macro_name)?)))
+                    Ok(builder(None, Some(expr), Some(parse_expression(input, macro_name)?)))
             }
         }
         Some(Verbosity::Verbose) => {
             if input.is_empty() {
-                Ok(builder(None, Some(expr)))
+                Ok(builder(None, None, Some(expr)))
             } else {
                 let error_span = input.span();
 
@@ -126,6 +188,122 @@ pub fn parse_expr_eval<T>(
     }
 }
 
+// parses `@quite => expr, @terse => expr, @verbose => expr` arms, the arms are exhaustive so
+// every verbosity requires an arm
+#[cfg(any(feature = "eval", feature = "release"))]
+fn parse_expr_arms(input: ParseStream) -> syn::Result<(Expr, Expr, Expr)> {
+    let mut quite = None;
+    let mut terse = None;
+    let mut verbose = None;
+    // missing arms are reported at the quiet arm, or at the last arm if there is no quiet arm
+    let mut missing_span = input.span();
+
+    loop {
+        let error_span = input.span();
+        let verbosity = parse_arm_verbosity(input)?;
+
+        if verbosity == Verbosity::Quite || quite.is_none() {
+            missing_span = error_span;
+        }
+
+        <Token![=>]>::parse(input)?;
+
+        let expr = Some(<Expr>::parse(input)?);
+
+        let ordering_err = match verbosity {
+            Verbosity::Quite if quite.is_some() =>
+                Some(DUPE_VERBOSITY_ERR),
+            Verbosity::Quite if terse.is_some() || verbose.is_some() =>
+                Some(QUITE_ORDER_ERR),
+            Verbosity::Terse if terse.is_some() =>
+                Some(DUPE_VERBOSITY_ERR),
+            Verbosity::Terse if verbose.is_some() =>
+                Some(VERBOSITY_ORDER_ERR),
+            Verbosity::Verbose if verbose.is_some() =>
+                Some(DUPE_VERBOSITY_ERR),
+            _ =>
+                None
+        };
+
+        if let Some(err) = ordering_err {
+            return Err(Error::new(error_span, err));
+        }
+
+        match verbosity {
+            Verbosity::Quite => quite = expr,
+            Verbosity::Terse => terse = expr,
+            Verbosity::Verbose => verbose = expr
+        }
+
+        if input.peek(Token![,]) { <Token![,]>::parse(input)?; }
+        if input.is_empty() { break; }
+    }
+
+    match (quite, terse, verbose) {
+        (Some(quite), Some(terse), Some(verbose)) => Ok((quite, terse, verbose)),
+        (quite, terse, verbose) => {
+            let missing = [(quite, "@quiet"), (terse, "@terse"), (verbose, "@verbose")]
+                .into_iter()
+                .filter_map(|(arm, name)| arm.is_none().then_some(name))
+                .collect::<Vec<_>>();
+
+            let missing = match missing.as_slice() {
+                [arm] => format!("missing {arm} arm"),
+                [arms @ .., last] => format!("missing {} and {last} arms", arms.join(", ")),
+                [] => unreachable!("arms are missing")
+            };
+
+            Err(Error::new(missing_span, missing))
+        }
+    }
+}
+
+#[cfg(any(feature = "eval", feature = "release"))]
+fn parse_arm_verbosity(input: ParseStream) -> syn::Result<Verbosity> {
+    let span = input.span();
+
+    if input.peek(Token![@]) {
+        <Token![@]>::parse(input)?;
+
+        if input.peek(kw::quiet) {
+            <kw::quiet>::parse(input)?;
+
+            return Ok(Verbosity::Quite);
+        } else if input.peek(kw::quite) {
+            <kw::quite>::parse(input)?;
+
+            return Ok(Verbosity::Quite);
+        } else if input.peek(kw::terse) {
+            <kw::terse>::parse(input)?;
+
+            return Ok(Verbosity::Terse);
+        } else if input.peek(kw::verbose) {
+            <kw::verbose>::parse(input)?;
+
+            return Ok(Verbosity::Verbose);
+        }
+    }
+
+    Err(Error::new(span, "expecting a verbosity arm, i.e. @quiet => expr, @terse => expr or @verbose => expr"))
+}
+
+// parses an optional `@level(expr)` clause, the verbosity to use instead of the global verbosity
+#[cfg(any(feature = "eval", feature = "release", feature = "report"))]
+pub fn parse_level(input: ParseStream) -> syn::Result<Option<Expr>> {
+    if !(input.peek(Token![@]) && input.peek2(kw::level)) {
+        return Ok(None);
+    }
+
+    <Token![@]>::parse(input)?;
+    <kw::level>::parse(input)?;
+
+    let level;
+
+    parenthesized!(level in input);
+
+    Ok(Some(level.parse()?))
+}
+
 #[cfg(any(feature = "debug", feature = "eval", feature = "release"))]
 pub fn parse_expression(input: ParseStream, macro_name: &str) -> syn::Result<Expr> {
     let expr = <Expr>::parse(input)?;
@@ -140,10 +318,12 @@ pub fn parse_expression(input: ParseStream, macro_name: &str) -> syn::Result<Exp
         Expr::Closure(_) | Expr::Continue(_) |
         Expr::Field(_) | Expr::Group(_) |
         Expr::If(_) | Expr::Index(_) |
-        Expr::Macro(_) | Expr::Match(_) |
-        Expr::MethodCall(_) | Expr::Path(_) |
-        Expr::Reference(_) | Expr::Repeat(_) |
-        Expr::Return(_) | Expr::Try(_) |
+        Expr::Lit(_) | Expr::Macro(_) |
+        Expr::Match(_) | Expr::MethodCall(_) |
+        Expr::Paren(_) | Expr::Path(_) |
+        Expr::Range(_) | Expr::Reference(_) |
+        Expr::Repeat(_) | Expr::Return(_) |
+        Expr::Struct(_) | Expr::Try(_) |
         Expr::Tuple(_) | Expr::Unary(_) =>
             parse_optional_semicolon(input, false)?,
         _ =>
@@ -201,6 +381,8 @@ pub fn parse_verbosity(input: ParseStream, chk_semicolon: bool) -> syn::Result<O
     Ok(verbosity)
 }
 
+// arguments are passed through to `format_args!` as they are, which validates them, so
+// positional, named, i.e. `name = expr`, and width or precision arguments are all accepted
 #[cfg(any(feature = "debug", feature = "report"))]
 fn parse_args(input: ParseStream) -> syn::Result<Option<Vec<Expr>>> {
     let mut exprs = Vec::new();
@@ -208,29 +390,10 @@ fn parse_args(input: ParseStream) -> syn::Result<Option<Vec<Expr>>> {
     while input.peek(Token![,]) {
         <Token![,]>::parse(input)?;
 
-        let expr = <Expr>::parse(input)?;
-
-        match expr {
-            Expr::Array(_) | Expr::Await(_) |
-            Expr::Binary(_) | Expr::Block(_) |
-            Expr::Call(_) | Expr::Cast(_) |
-            Expr::Field(_) | Expr::Group(_) |
-            Expr::If(_) | Expr::Index(_) |
-            Expr::Lit(_) | Expr::Macro(_) |
-            Expr::Match(_) | Expr::MethodCall(_) |
-            Expr::Paren(_) | Expr::Path(_) |
-            Expr::Range(_) | Expr::Reference(_) |
-            Expr::Repeat(_) | Expr::Try(_) |
-            Expr::TryBlock(_) | Expr::Tuple(_) |
-            Expr::Unary(_) | Expr::Unsafe(_) => {}
-            _ =>
-                return Err(Error::new(
-                    expr.span(),
-                    format!("{:?} is not a supported arg expression", decode_expr_type(&expr)),
-                ))
-        }
+        // allow a trailing comma
+        if input.is_empty() || input.peek(Token![;]) { break; }
 
-        exprs.push(expr);
+        exprs.push(<Expr>::parse(input)?);
     }
 
     parse_optional_semicolon(input, true)?;
@@ -238,16 +401,38 @@ fn parse_args(input: ParseStream) -> syn::Result<Option<Vec<Expr>>> {
     Ok(if exprs.is_empty() { None } else { Some(exprs) })
 }
 
+// a message starts with a format string or prebuilt arguments
+#[cfg(feature = "debug")]
+pub fn peek_message(input: ParseStream) -> bool {
+    input.peek(LitStr) || input.peek(LitByteStr) || peek_arguments(input) || peek_format_macro(input)
+}
+
 #[cfg(any(feature = "debug", feature = "report"))]
-fn parse_format(input: ParseStream) -> syn::Result<Lit> {
-    let literal = <Lit>::parse(input)?;
+fn parse_format(input: ParseStream) -> syn::Result<Format> {
+    if peek_format_macro(input) {
+        return Ok(Format::Macro(input.parse()?));
+    }
+
+    let span = input.span();
 
-    match literal {
-        Lit::Str(_) | Lit::ByteStr(_) => {}
-        _ => return Err(Error::new(literal.span(), "expecting a string literal"))
+    match input.parse() {
+        Ok(literal @ (Lit::Str(_) | Lit::ByteStr(_))) => Ok(Format::Lit(literal)),
+        _ => Err(Error::new(span, FORMAT_ERR))
     }
+}
 
-    Ok(literal)
+#[cfg(any(feature = "debug", feature = "report"))]
+fn peek_arguments(input: ParseStream) -> bool {
+    input.peek(kw::args) && input.peek2(Token![:]) && !input.peek2(Token![::])
+}
+
+// a macro that expands to a string literal, i.e. `concat!(PREFIX, "{}")`
+#[cfg(any(feature = "debug", feature = "report"))]
+fn peek_format_macro(input: ParseStream) -> bool {
+    input.fork().parse::<Macro>().is_ok_and(|mac| {
+        mac.path.segments.last()
+            .is_some_and(|segment| FORMAT_MACROS.contains(&segment.ident.to_string().as_str()))
+    })
 }
 
 #[cfg(any(feature = "eval", feature = "release"))]
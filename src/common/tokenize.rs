@@ -1,9 +1,10 @@
+#[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
+use proc_macro2::{Ident, Span};
 use proc_macro2::TokenStream;
-#[cfg(any(feature = "debug", feature = "report"))]
 use quote::ToTokens;
-#[cfg(any(feature = "eval", feature = "release"))]
+#[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
 use syn::Expr;
-#[cfg(any(feature = "eval", feature = "release"))]
+#[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
 use verbosity::Verbosity;
 
 #[cfg(any(feature = "debug", feature = "report"))]
@@ -16,37 +17,145 @@ impl ToTokens for Message {
     }
 }
 
+// evaluates to an `Option` of the value of the expression intended for the active verbosity,
+// `None` at quite or when no expression is intended for the active verbosity
 #[cfg(any(feature = "eval", feature = "release"))]
-pub fn tokenize_expression(terse: &Option<Expr>, verbose: &Option<Expr>) -> TokenStream {
-    match (terse, verbose) {
-        (Some(terse), None) =>
-            tokenize_verbosity_expression(Verbosity::Terse, terse),
-        (None, Some(verbose)) =>
-            tokenize_verbosity_expression(Verbosity::Verbose, verbose),
+pub fn tokenize_expression(
+    level: Option<&Expr>, terse: Option<&Expr>, verbose: Option<&Expr>,
+) -> TokenStream {
+    tokenize_with_level(level, match (terse, verbose) {
+        (Some(terse), None) => {
+            let verbosity_check = tokenize_verbosity_check(Verbosity::Terse, level);
+
+            let terse = tokenize_some(terse);
+
+            quote! { if #verbosity_check { #terse } else { std::option::Option::None } }
+        }
+        (None, Some(verbose)) => {
+            let verbosity_check = tokenize_verbosity_check(Verbosity::Verbose, level);
+
+            let verbose = tokenize_some(verbose);
+
+            quote! { if #verbosity_check { #verbose } else { std::option::Option::None } }
+        }
         (Some(terse), Some(verbose)) => {
-            let terse = tokenize_verbosity_expression(Verbosity::Terse, terse);
-            let verbose = tokenize_verbosity_expression(Verbosity::Verbose, verbose);
+            let terse = tokenize_some(terse);
+            let verbose = tokenize_some(verbose);
+            let active_level = tokenize_active_level(level);
 
             quote! {
-                match verbosity::Verbosity::level() {
+                match #active_level {
                     verbosity::Verbosity::Terse => #terse,
                     verbosity::Verbosity::Verbose => #verbose,
-                    verbosity::Verbosity::Quite => {}
+                    verbosity::Verbosity::Quite => std::option::Option::None
                 }
             }
         }
-        (None, None) => TokenStream::new()
-    }
+        (None, None) => quote! { std::option::Option::None }
+    })
+}
+
+// evaluates to the value of the arm intended for the active verbosity, the arms are exhaustive
+#[cfg(any(feature = "eval", feature = "release"))]
+pub fn tokenize_arms(level: Option<&Expr>, quite: &Expr, terse: &Expr, verbose: &Expr) -> TokenStream {
+    let active_level = tokenize_active_level(level);
+
+    tokenize_with_level(level, quote! {
+        match #active_level {
+            verbosity::Verbosity::Quite => #quite,
+            verbosity::Verbosity::Terse => #terse,
+            verbosity::Verbosity::Verbose => #verbose
+        }
+    })
 }
 
+// evaluates to the `Option` of the value, unless the value is `()`, which evaluates to `()` so the
+// macro can still be used as a statement, see `cli_toolbox_runtime::Evaluated`
 #[cfg(any(feature = "eval", feature = "release"))]
-pub fn tokenize_verbosity_expression(verbosity: Verbosity, expr: &Expr) -> TokenStream {
-    let verbosity_check = if verbosity == Verbosity::Terse {
-        quote! { verbosity::Verbosity::is_terse() }
+pub fn tokenize_evaluated(option: &TokenStream) -> TokenStream {
+    quote! {
+        {
+            #[allow(unused_imports)]
+            use cli_toolbox_runtime::{EvaluatedOption as _, EvaluatedUnit as _};
+
+            (&&cli_toolbox_runtime::Evaluated::new(#option)).value()
+        }
+    }
+}
+
+// the active verbosity, either the level provided with `@level`, the scoped verbosity of the
+// current thread, the level the verbosity filter assigns to the module of the call site or the
+// global verbosity
+#[cfg(any(feature = "eval", feature = "release", feature = "report"))]
+pub fn tokenize_active_level(level: Option<&Expr>) -> TokenStream {
+    if level.is_some() {
+        let level = level_ident();
+
+        quote! { #level }
     } else {
-        quote! { verbosity::Verbosity::is_verbose() }
-    };
+        quote! { cli_toolbox_runtime::level(module_path!()) }
+    }
+}
+
+// evaluates if the intended verbosity matches the active verbosity, quite is never implied
+// by another level so it is checked for specifically
+#[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
+pub fn tokenize_verbosity_check(verbosity: Verbosity, level: Option<&Expr>) -> TokenStream {
+    if level.is_some() {
+        let level = level_ident();
+
+        return match verbosity {
+            Verbosity::Quite =>
+                quote! { matches!(#level, verbosity::Verbosity::Quite) },
+            Verbosity::Terse =>
+                quote! { matches!(#level, verbosity::Verbosity::Terse | verbosity::Verbosity::Verbose) },
+            Verbosity::Verbose =>
+                quote! { matches!(#level, verbosity::Verbosity::Verbose) }
+        };
+    }
+
+    match verbosity {
+        Verbosity::Quite => quote! { cli_toolbox_runtime::is_quite(module_path!()) },
+        Verbosity::Terse => quote! { cli_toolbox_runtime::is_terse(module_path!()) },
+        Verbosity::Verbose => quote! { cli_toolbox_runtime::is_verbose(module_path!()) }
+    }
+}
+
+// evaluates the level provided with `@level` once, before the tokens that check it
+#[cfg(any(feature = "eval", feature = "release", feature = "report"))]
+pub fn tokenize_with_level(level: Option<&Expr>, tokens: TokenStream) -> TokenStream {
+    match level {
+        Some(level) => {
+            let ident = level_ident();
+
+            quote! {
+                {
+                    let #ident: verbosity::Verbosity = #level;
+
+                    #tokens
+                }
+            }
+        }
+        None => tokens
+    }
+}
 
-    quote! { if #verbosity_check { #expr; } }
+// binding the value keeps a block expression from being linted as unnecessary braces
+#[cfg(any(feature = "eval", feature = "release"))]
+pub fn tokenize_some<T: ToTokens>(expr: &T) -> TokenStream {
+    let value = Ident::new("value", Span::mixed_site());
+
+    quote! {
+        {
+            let #value = #expr;
+
+            std::option::Option::Some(#value)
+        }
+    }
 }
 
+// mixed site hygiene keeps the provided level from clashing with identifiers in the macro input
+#[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
+fn level_ident() -> Ident {
+    Ident::new("level", Span::mixed_site())
+}
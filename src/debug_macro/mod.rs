@@ -6,34 +6,64 @@ use std::fmt::{Display, Formatter};
 #[cfg(all(debug_assertions, feature = "trace"))]
 use quote::ToTokens;
 use syn::Expr;
+use verbosity::Verbosity;
 
 use crate::common::Message;
 
 mod parse;
 mod tokenize;
 
-pub enum DebugMacro {
+pub struct DebugMacro {
+    debug: Debug,
+    std_err: bool,
+    // `None` outputs regardless of the active verbosity
+    verbosity: Option<Verbosity>,
+}
+
+enum Debug {
     Message(Message),
     Expr(Expr),
+    // inspects the value of the expression, like `std::dbg!`
+    Value(Expr),
 }
 
 #[cfg(all(debug_assertions, feature = "trace"))]
 impl Display for DebugMacro {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Message(msg) => write!(fmt, "debug! {{\n  message: {msg}\n}}"),
-            Self::Expr(expr) => write!(fmt, "debug! {{\n  expr: {}\n}}", expr.to_token_stream())
+        let verbosity = self.verbosity.as_ref().map_or_else(|| "None".to_string(), ToString::to_string);
+
+        match &self.debug {
+            Debug::Message(msg) => write!(
+                fmt, "debug! {{\n  message: {msg}\n  location: {}\n  std_err: {}\n  verbosity: {verbosity}\n}}",
+                msg.location, self.std_err
+            ),
+            Debug::Expr(expr) => write!(
+                fmt, "debug! {{\n  expr: {}\n  verbosity: {verbosity}\n}}",
+                expr.to_token_stream()
+            ),
+            Debug::Value(expr) => write!(
+                fmt, "debug! {{\n  value: {}\n  verbosity: {verbosity}\n}}",
+                expr.to_token_stream()
+            )
         }
     }
 }
 
 pub struct DebugLnMacro {
     message: Message,
+    std_err: bool,
+    // `None` outputs regardless of the active verbosity
+    verbosity: Option<Verbosity>,
 }
 
 #[cfg(all(debug_assertions, feature = "trace"))]
 impl Display for DebugLnMacro {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(fmt, "debugln! {{\n  message: {}\n}}", self.message)
+        let verbosity = self.verbosity.as_ref().map_or_else(|| "None".to_string(), ToString::to_string);
+
+        write!(
+            fmt, "debugln! {{\n  message: {}\n  location: {}\n  std_err: {}\n  verbosity: {verbosity}\n}}",
+            self.message, self.message.location, self.std_err
+        )
     }
-}
\ No newline at end of file
+}
@@ -1,9 +1,15 @@
+use syn::Error;
 use syn::parse::{Parse, ParseStream};
+use verbosity::Verbosity;
 
-use crate::common::Message;
-use crate::common::parse::parse_expression;
+use crate::common::{kw, Message};
+use crate::common::parse::{parse_expression, peek_message};
 use crate::common::tracing::{trace_parsed, trace_source};
-use crate::debug_macro::{DebugLnMacro, DebugMacro};
+use crate::debug_macro::{Debug, DebugLnMacro, DebugMacro};
+
+const EXPR_LOCATION_ERR: &str = "@loc can only be used with a message";
+
+const EXPR_STREAM_ERR: &str = "@err can only be used with a message";
 
 impl Parse for DebugLnMacro {
     fn parse(input: ParseStream) -> syn::Result<Self> {
@@ -11,7 +17,13 @@ impl Parse for DebugLnMacro {
 
         #[inline]
         fn parse(input: ParseStream) -> syn::Result<DebugLnMacro> {
-            Ok(DebugLnMacro { message: Message::parse(input, true)? })
+            let (std_err, verbosity) = parse_verbosity(input)?;
+            let location = parse_location(input)?;
+            let mut message = Message::parse(input, true)?;
+
+            message.location = location;
+
+            Ok(DebugLnMacro { message, std_err, verbosity })
         }
     }
 }
@@ -22,12 +34,83 @@ impl Parse for DebugMacro {
 
         #[inline]
         fn parse(input: ParseStream) -> syn::Result<DebugMacro> {
-            Ok(if let Ok(message) = Message::parse(input, false) {
-                DebugMacro::Message(message)
+            let span = input.span();
+            let (std_err, verbosity) = parse_verbosity(input)?;
+            let location = parse_location(input)?;
+
+            let debug = if input.peek(Token![=]) && !input.peek(Token![==]) {
+                <Token![=]>::parse(input)?;
+
+                Debug::Value(input.parse()?)
+            } else if peek_message(input) {
+                let mut message = Message::parse(input, false)?;
+
+                message.location = location;
+
+                Debug::Message(message)
+            } else if std_err {
+                return Err(Error::new(span, EXPR_STREAM_ERR));
+            } else if location {
+                return Err(Error::new(span, EXPR_LOCATION_ERR));
             } else {
-                DebugMacro::Expr(parse_expression(input, "debug")?)
-            })
+                Debug::Expr(parse_expression(input, "debug")?)
+            };
+
+            Ok(DebugMacro { debug, std_err, verbosity })
         }
     }
 }
 
+// an optional flag, `@loc`, that prefixes a message with the source location of the call
+fn parse_location(input: ParseStream) -> syn::Result<bool> {
+    if input.peek(Token![@]) && input.peek2(kw::loc) {
+        <Token![@]>::parse(input)?;
+        <kw::loc>::parse(input)?;
+
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+// an optional verbosity designation, without one the output ignores the active verbosity,
+// `@err` alone only designates the stream
+fn parse_verbosity(input: ParseStream) -> syn::Result<(bool, Option<Verbosity>)> {
+    let mut std_err = false;
+    let span = input.span();
+
+    if !input.peek(Token![@]) || input.peek2(kw::loc) {
+        return Ok((std_err, None));
+    }
+
+    <Token![@]>::parse(input)?;
+
+    if input.peek(kw::err) {
+        <kw::err>::parse(input)?;
+
+        std_err = true;
+
+        if !input.peek(Token![-]) {
+            return Ok((std_err, None));
+        }
+
+        <Token![-]>::parse(input)?;
+    }
+
+    let verbosity = if input.peek(kw::terse) {
+        <kw::terse>::parse(input)?;
+
+        Verbosity::Terse
+    } else if input.peek(kw::verbose) {
+        <kw::verbose>::parse(input)?;
+
+        Verbosity::Verbose
+    } else {
+        return Err(Error::new(
+            span,
+            "invalid verbosity designation, use @err, @terse, @verbose, @err-terse, @err-verbose or leave blank to ignore verbosity",
+        ));
+    };
+
+    Ok((std_err, Some(verbosity)))
+}
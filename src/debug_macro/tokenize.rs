@@ -1,37 +1,82 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
+use syn::{Expr, Lit, LitStr};
+use verbosity::Verbosity;
 
-use crate::common::Message;
+use crate::common::{Format, Message};
+use crate::common::tokenize::tokenize_verbosity_check;
 use crate::common::tracing::trace_expansion;
-use crate::debug_macro::{DebugLnMacro, DebugMacro};
+use crate::debug_macro::{Debug, DebugLnMacro, DebugMacro};
+
+// source text and pretty printed value of an inspected expression
+#[allow(clippy::literal_string_with_formatting_args)]
+const VALUE_FMT: &str = "{} = {:#?}";
 
 impl ToTokens for DebugLnMacro {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(trace_expansion(tokenize_debug_message_macro(&self.message)));
+        tokens.extend(trace_expansion(
+            tokenize_debug_message_macro(&self.message, self.std_err, self.verbosity)
+        ));
     }
 }
 
 impl ToTokens for DebugMacro {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(trace_expansion(
-            match self {
-                Self::Message(message) =>
-                    tokenize_debug_message_macro(message),
-                Self::Expr(expr) =>
-                    quote! {
-                    #[cfg(debug_assertions)]
-                    { #expr; }
-                }
+            match &self.debug {
+                Debug::Message(message) =>
+                    tokenize_debug_message_macro(message, self.std_err, self.verbosity),
+                Debug::Expr(expr) =>
+                    tokenize_debug_macro(&quote! { #expr; }, self.verbosity),
+                Debug::Value(expr) =>
+                    tokenize_debug_value_macro(expr, self.verbosity)
             }
         ));
     }
 }
 
-fn tokenize_debug_message_macro(message: &Message) -> TokenStream {
-    let message = message.build_message(false);
+fn tokenize_debug_message_macro(
+    message: &Message, std_err: bool, verbosity: Option<Verbosity>,
+) -> TokenStream {
+    let message = message.build_message(std_err);
+
+    tokenize_debug_macro(&quote! { #message; }, verbosity)
+}
+
+// evaluates to the value of the expression, which is printed to `io::stderr` with its source text
+// and location, like `std::dbg!`, only when compiled unoptimized
+fn tokenize_debug_value_macro(expr: &Expr, verbosity: Option<Verbosity>) -> TokenStream {
+    // mixed site hygiene keeps the value from clashing with identifiers in the expression
+    let value = Ident::new("value", Span::mixed_site());
+    let fmt = Format::Lit(Lit::Str(LitStr::new(VALUE_FMT, Span::call_site())));
+    let source = parse_quote! { stringify!(#expr) };
+    let mut message = Message::new(fmt, Some(vec![source, parse_quote! { #value }]), true);
+
+    message.location = true;
+
+    let debug = tokenize_debug_message_macro(&message, true, verbosity);
+
+    quote! {
+        {
+            let #value = #expr;
+
+            #debug
+
+            #value
+        }
+    }
+}
+
+// debugging output is only compiled unoptimized and, when designated, checks the active verbosity
+fn tokenize_debug_macro(debug: &TokenStream, verbosity: Option<Verbosity>) -> TokenStream {
+    let debug = verbosity.map_or_else(|| debug.clone(), |verbosity| {
+        let verbosity_check = tokenize_verbosity_check(verbosity, None);
+
+        quote! { if #verbosity_check { #debug } }
+    });
 
     quote! {
         #[cfg(debug_assertions)]
-        { #message; }
+        { #debug }
     }
-}
\ No newline at end of file
+}
@@ -11,6 +11,8 @@ mod parse;
 mod tokenize;
 
 pub struct Eval {
+    level: Option<Expr>,
+    quite: Option<Expr>,
     terse: Option<Expr>,
     verbose: Option<Expr>,
 }
@@ -20,7 +22,13 @@ impl Display for Eval {
     //noinspection ALL
     fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
-            fmt, "eval! {{\n  terse: {}\n  verbose: {}\n}}",
+            fmt, "eval! {{\n  level: {}\n  quite: {}\n  terse: {}\n  verbose: {}\n}}",
+            self.level.as_ref().map_or_else(
+                || "None".to_string(), |e| (*e).to_token_stream().to_string(),
+            ),
+            self.quite.as_ref().map_or_else(
+                || "None".to_string(), |e| (*e).to_token_stream().to_string(),
+            ),
             self.terse.as_ref().map_or_else(
                 || "None".to_string(), |e| (*e).to_token_stream().to_string(),
             ),
@@ -1,13 +1,20 @@
 use syn::parse::{Parse, ParseStream};
 
-use crate::common::parse::parse_expr_eval;
+use crate::common::parse::{parse_expr_eval, parse_level};
 use crate::common::tracing::{trace_parsed, trace_source};
 use crate::eval_macro::Eval;
 
 impl Parse for Eval {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        trace_parsed(parse_expr_eval(
-            trace_source(input), "eval", |terse, verbose| Self { terse, verbose },
-        ))
+        return trace_parsed(parse(trace_source(input)));
+
+        #[inline]
+        fn parse(input: ParseStream) -> syn::Result<Eval> {
+            let level = parse_level(input)?;
+
+            parse_expr_eval(input, "eval", |quite, terse, verbose| Eval {
+                level, quite, terse, verbose,
+            })
+        }
     }
 }
\ No newline at end of file
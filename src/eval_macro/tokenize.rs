@@ -1,12 +1,18 @@
 use proc_macro2::TokenStream;
 use quote::ToTokens;
 
-use crate::common::tokenize::tokenize_expression;
+use crate::common::tokenize::{tokenize_arms, tokenize_evaluated, tokenize_expression};
 use crate::common::tracing::trace_expansion;
 use crate::eval_macro::Eval;
 
 impl ToTokens for Eval {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(trace_expansion(tokenize_expression(&self.terse, &self.verbose)));
+        tokens.extend(trace_expansion(match (&self.quite, &self.terse, &self.verbose) {
+            (Some(quite), Some(terse), Some(verbose)) =>
+                tokenize_arms(self.level.as_ref(), quite, terse, verbose),
+            _ => tokenize_evaluated(&tokenize_expression(
+                self.level.as_ref(), self.terse.as_ref(), self.verbose.as_ref(),
+            ))
+        }));
     }
 }
@@ -7,7 +7,7 @@
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::items_after_statements)]
 // ==============================================================
-#![doc(html_root_url = "https://docs.rs/cli-toolbox/0.8.1")]
+#![doc(html_root_url = "https://docs.rs/cli-toolbox/0.9.0")]
 
 //! Utility library for working with ```cli``` output ergonomically.
 //!
@@ -21,10 +21,23 @@
 //!
 //! ### Output Macros
 //!
-//! * `debug!` - conditionally compiled console debugging output - \[`debug`\]
+//! * `debug!` - conditionally compiled console debugging output, optionally according to
+//!   verbosity level - \[`debug`\]
 //!
 //! * `report!` - conditional console output according to verbosity level - \[`debug`|`release`\]
 //!
+//! * `report_block!` - conditional console output of lines as a single block - \[`debug`|`release`\]
+//!
+//! * `try_report!` - fallible conditional console output according to verbosity level - \[`debug`|`release`\]
+//!
+//! * `error!`, `warn!`, `info!`, `success!` and `note!` - labeled and styled console output
+//!   according to verbosity level - \[`debug`|`release`\]
+//!
+//! * `bail!` and `exit_with!` - conditional console output according to verbosity level, then
+//!   returns an error or exits - \[`debug`|`release`\]
+//!
+//! * `progress!` - progress of a task drawn according to verbosity level - \[`debug`|`release`\]
+//!
 //! \* _debug! is intended to be used during application development_
 //!
 //! \* _all other debugging and telemetry output is most likely better served with a logging library_
@@ -34,6 +47,83 @@
 //! * `eval!` - conditional code execution according to verbosity level - \[`debug`|`release`\]
 //!
 //! * `release!` - conditional code execution according to verbosity level - \[`release`\]
+//!
+//! Both macros evaluate to the value of the code they execute
+//!
+//! * `eval!` evaluates to an `Option<T>`, `None` at quiet, or to `T` with an arm for every verbosity
+//! * `release!` always evaluates to an `Option<T>`, `None` at quiet and in debug builds
+//! * a `T` of `()` evaluates to `()`, so both macros can be used as statements
+//!
+//! ### Verbosity Filters
+//!
+//! The `CLI_VERBOSITY` environment variable can assign a verbosity level to specific modules,
+//! overriding the global verbosity level for the `debug!`, `eval!`, `release!` and `report!`
+//! macros used in those modules, i.e. `CLI_VERBOSITY=my_cli::net=verbose,my_cli::db=quiet`
+//!
+//! * the most specific module path applies, including to its sub-modules
+//! * an entry without a module path, i.e. `terse`, applies to all modules
+//! * modules that are not filtered use the global verbosity level
+//!
+//! _\* the filter is read once, the first time a macro is evaluated_
+//!
+//! ### Scoped Verbosity
+//!
+//! The verbosity of the current thread can be overridden for the duration of a closure with
+//! `cli_toolbox_runtime::with_verbosity`, or for the lifetime of a
+//! `cli_toolbox_runtime::VerbosityGuard`, i.e. to report one job of a thread pool verbosely while
+//! the other jobs stay terse
+//!
+//! * a level provided with `@level(expr)` applies first
+//! * then the scoped verbosity of the current thread
+//! * then the level the verbosity filters assign to the module
+//! * and finally the global verbosity level
+//!
+//! ### JSON Output
+//!
+//! Setting the `CLI_TOOLBOX_OUTPUT_FORMAT` environment variable to `json` makes the `report!`
+//! macros write each message as a [JSON Lines] object instead of text, `human` or not setting it
+//! writes text. The environment variable is read once, the first time a `report!` macro is called,
+//! an application can override it with `cli_toolbox_runtime::set_output_format`. Each call reads
+//! the output format once, so all of its messages are written in the same format.
+//!
+//! ```text
+//! {"version":1,"level":"verbose","stream":"stderr","message":"...","module":"my_cli::net"}
+//! ```
+//!
+//! * `version` - version of this schema, currently `1`
+//! * `level` - intended verbosity of the message, `always`, `quiet`, `terse` or `verbose`
+//! * `stream` - `stdout`, `stderr` or `writer` for an `@to` writer
+//! * `message` - formatted message, without styles
+//! * `module` - module path of the macro call
+//!
+//! _\* every object is written on its own line, even if the message does not append a new line_
+//!
+//! ### Output Policy
+//!
+//! By default the `debug!` and `report!` macros panic, like [`println!`], when a reader of
+//! `io::stdout` or `io::stderr` closes its end of a pipe, i.e. `my-cli | head`.
+//!
+//! The `CLI_TOOLBOX_OUTPUT_POLICY` environment variable sets the policy for when that happens,
+//! it is read once, the first time a macro writes output that fails. The application can set
+//! the policy at runtime with `cli_toolbox_runtime::set_output_policy`, which overrides the
+//! environment variable
+//!
+//! * `exit` - exits the process quietly with the conventional `SIGPIPE` exit code, `141`
+//! * `ignore` - ignores the closed pipe and continues execution
+//! * `panic` - panics, same as when it is not set
+//!
+//! _\* any other output failure always panics_
+//!
+//! _\* with the `panic` policy output is printed with [`print!`] and [`eprint!`], so it is
+//! captured by the test harness, except for byte string messages that are not valid UTF-8_
+//!
+//! _\* the policy does not apply to `@to` writers or the `try_report!` macros, `@to` writers
+//! panic and the `try_report!` macros return the error_
+//!
+//! [JSON Lines]: <https://jsonlines.org>
+//! [`println!`]: <https://doc.rust-lang.org/std/macro.println.html>
+//! [`print!`]: <https://doc.rust-lang.org/std/macro.print.html>
+//! [`eprint!`]: <https://doc.rust-lang.org/std/macro.eprint.html>
 
 #[cfg(any(feature = "debug", feature = "eval", feature = "release", feature = "report"))]
 #[macro_use]
@@ -62,14 +152,31 @@ mod report_macro;
 #[cfg(test)]
 mod tests;
 
-/// Conditionally prints to `io::stdout` or evaluates an expression when the code
-/// is compiled unoptimized with debug assertions, otherwise does not include
-/// the message or expression. _for a message a new line is not appended_
+/// Conditionally prints, evaluates or inspects an expression in unoptimized debug builds.
+///
+/// When the code is compiled without debug assertions the message or expression is not
+/// included. _for a message a new line is not appended_
 ///
 /// ## Anatomy of the `debug!` macro
 ///
 /// In order to print to `io:stdout`, `debug!` accepts the same input as the `std`
-/// library [`print!`] macro. Otherwise it accepts a valid expression.
+/// library [`print!`] macro, or prebuilt `fmt::Arguments` with `args: expr`. Otherwise it
+/// accepts a valid expression.
+///
+/// A byte string message is written as raw bytes, its `{}` placeholders are replaced by the
+/// raw bytes of `[u8]`, `str`, `OsStr` or `Path` arguments, i.e. non-UTF-8 file names.
+///
+/// Either can be preceded by an optional intended verbosity, `@terse` or `@verbose`,
+/// in which case it is only included when the intended verbosity matches the active
+/// verbosity. A message can be printed to `io::stderr` with `@err`, `@err-terse` or
+/// `@err-verbose`, and prefixed with the `file!():line!()` and `module_path!()` of the
+/// call with `@loc`, which follows any designation.
+///
+/// An expression preceded by `=` is inspected like the `std` library [`dbg!`] macro, its
+/// source text and `{:#?}` value are printed to `io::stderr` with the source location of
+/// the call, then it evaluates to the value. When the code is optimized it evaluates to
+/// the value of the expression only, so unlike an expression without `=`, which is not
+/// included at all, the expression is still evaluated in release builds.
 ///
 /// ### Examples
 /// * Printing to `io::stdout`
@@ -79,6 +186,28 @@ mod tests;
 /// debug! { "DBG: debugging information - {}", 42 }
 /// ```
 ///
+/// * Printing to `io::stderr` when verbose
+///
+/// ```no_run
+/// # use::cli_toolbox::debug;
+/// debug! { @err-verbose "DBG: elaborate debugging information - {}", 42 }
+/// ```
+///
+/// * Printing to `io::stderr` with the source location, i.e. `[src/main.rs:4 my_cli] DBG: ..`
+///
+/// ```no_run
+/// # use::cli_toolbox::debug;
+/// debug! { @err @loc "DBG: debugging information - {}", 42 }
+/// ```
+///
+/// * Printing raw bytes to `io::stderr`
+///
+/// ```no_run
+/// # use::cli_toolbox::debug;
+/// # let path = std::path::Path::new("target/output.txt");
+/// debug! { @err b"DBG: writing to {}", path }
+/// ```
+///
 /// * Evaluating Expression
 ///
 /// ```no_run
@@ -87,11 +216,31 @@ mod tests;
 /// # fn validate_some_important_such_and_such() {}
 /// ```
 ///
+/// * Inspecting the value of an expression, i.e. `[src/main.rs:4 my_cli] answer(6, 7) = 42`
+///
+/// ```no_run
+/// # use::cli_toolbox::debug;
+/// let answer = debug! { =answer(6, 7) };
+/// # fn answer(lhs: u8, rhs: u8) -> u8 { lhs * rhs }
+/// ```
+///
+/// * Evaluating Expression when terse or verbose
+///
+/// ```no_run
+/// # use::cli_toolbox::debug;
+/// debug! { @terse validate_some_important_such_and_such(); }
+/// # fn validate_some_important_such_and_such() {}
+/// ```
+///
 /// ## Panics
 ///
 /// Just like the [`print!`] macros used to write the output, this also panics if
-/// writing to `io::stdout` fails.
+/// writing to `io::stdout` or `io::stderr` fails.
 ///
+/// _\* writing to a closed `io::stdout` or `io::stderr` pipe is subject to the [output policy]_
+///
+/// [output policy]: <index.html#output-policy>
+/// [`dbg!`]: <https://doc.rust-lang.org/std/macro.dbg.html>
 /// [`print!`]: <https://doc.rust-lang.org/std/macro.print.html>
 #[cfg(feature = "debug")]
 #[proc_macro]
@@ -99,27 +248,39 @@ pub fn debug(input: TokenStream) -> TokenStream {
     parse_macro_input!(input as debug_macro::DebugMacro).into_token_stream().into()
 }
 
-/// Conditionally prints to `io::stdout` when the code is compiled unoptimized
+/// Conditionally prints to `io::stdout` or `io::stderr` when the code is compiled unoptimized
 /// with debug assertions, otherwise does not include the message. _a new line is appended_
 ///
 /// ## Anatomy of the `debugln!` macro
 ///
-/// `debug!` accepts the same input as the `std` library [`println!`] macro.
+/// `debugln!` accepts the same input as the `std` library [`println!`] macro, preceded
+/// by an optional intended verbosity, `@err`, `@terse`, `@verbose`, `@err-terse` or
+/// `@err-verbose`, and an optional `@loc` to prefix the source location of the call.
 ///
-/// ### Example
+/// ### Examples
 ///
-/// Printing a line to `io::stdout`
+/// * Printing a line to `io::stdout`
 ///
 /// ```no_run
 /// # use::cli_toolbox::debugln;
 /// debugln! { "DBG: debugging information - {}", 42 }
 /// ```
 ///
+/// * Printing a line to `io::stderr` when terse or verbose
+///
+/// ```no_run
+/// # use::cli_toolbox::debugln;
+/// debugln! { @err-terse "DBG: debugging information - {}", 42 }
+/// ```
+///
 /// ## Panics
 ///
 /// Just like the [`println!`] macros used to write the output, this also panics if
-/// writing to `io::stdout` fails.
+/// writing to `io::stdout` or `io::stderr` fails.
 ///
+/// _\* writing to a closed `io::stdout` or `io::stderr` pipe is subject to the [output policy]_
+///
+/// [output policy]: <index.html#output-policy>
 /// [`println!`]: <https://doc.rust-lang.org/std/macro.println.html>
 #[cfg(feature = "debug")]
 #[proc_macro]
@@ -165,6 +326,49 @@ pub fn debugln(input: TokenStream) -> TokenStream {
 /// # fn foo(_value: usize) {}
 /// ```
 ///
+/// * Evaluates when the provided `Verbosity`, instead of the global `Verbosity`, is `verbose`
+/// ```no_run
+/// # use::cli_toolbox::eval;
+/// # use verbosity::Verbosity;
+/// # let bar = 42;
+/// # let job_verbosity = Verbosity::Verbose;
+/// eval! { @level(job_verbosity) @verbose foo(bar); }
+/// # fn foo(_value: usize) {}
+/// ```
+///
+/// ### _Values_
+///
+/// The macro evaluates to an `Option` of the value of the expression intended for the active
+/// verbosity, or `None` if nothing was evaluated, which is always the case at `quite`, so the
+/// `@terse` and `@verbose` expressions must evaluate to the same type. An expression that
+/// evaluates to `()`, i.e. an assignment, makes the macro evaluate to `()`, so it can be used as
+/// a statement. The value of any other expression has to be used, or discarded with `let _ =`.
+///
+/// ```no_run
+/// # use::cli_toolbox::eval;
+/// # let bar = 42;
+/// let detail: Option<String> = eval! {
+///     @terse foo(bar)
+///     @verbose format!("{} from {}", foo(bar), bar)
+/// };
+/// # fn foo(value: usize) -> String { value.to_string() }
+/// ```
+///
+/// Expressions designated with `=>` arms, separated by commas, are exhaustive, a `@quiet` arm,
+/// also accepted as `@quite`, is evaluated only if the `Verbosity` level is `quite`. It must be
+/// defined before the `@terse` and `@verbose` arms, which are both required, and the macro
+/// evaluates to the value of the arm instead of an `Option`.
+///
+/// ```no_run
+/// # use::cli_toolbox::eval;
+/// # let path = "target/output.txt";
+/// eval! {
+///     @quiet => println!("{}", path),
+///     @terse => println!("created {}", path),
+///     @verbose => println!("created output file {} with default settings", path)
+/// }
+/// ```
+///
 /// [`Verbosity`]: <https://crates.io/crates/verbosity>
 #[cfg(feature = "eval")]
 #[proc_macro]
@@ -211,6 +415,53 @@ pub fn eval(input: TokenStream) -> TokenStream {
 /// # fn foo(_value: usize) {}
 /// ```
 ///
+/// * Evaluates when the provided `Verbosity`, instead of the global `Verbosity`, is `verbose`
+/// ```no_run
+/// # use::cli_toolbox::release;
+/// # use verbosity::Verbosity;
+/// # let bar = 42;
+/// # let job_verbosity = Verbosity::Verbose;
+/// release! { @level(job_verbosity) @verbose foo(bar); }
+/// # fn foo(_value: usize) {}
+/// ```
+///
+/// ### _Values_
+///
+/// The macro evaluates to an `Option` of the value of the expression intended for the active
+/// verbosity, or `None` if nothing was evaluated, which is always the case at `quite` and in
+/// debug builds\*, so the `@terse` and `@verbose` expressions must evaluate to the same type. An
+/// expression that evaluates to `()`, i.e. an assignment, makes the macro evaluate to `()`, so it
+/// can be used as a statement. The value of any other expression has to be used, or discarded
+/// with `let _ =`.
+///
+/// ```no_run
+/// # use::cli_toolbox::release;
+/// # let bar = 42;
+/// let detail: Option<String> = release! {
+///     @terse foo(bar)
+///     @verbose format!("{} from {}", foo(bar), bar)
+/// };
+/// # fn foo(value: usize) -> String { value.to_string() }
+/// ```
+///
+/// Expressions designated with `=>` arms, separated by commas, are exhaustive, a `@quiet` arm,
+/// also accepted as `@quite`, is evaluated only if the `Verbosity` level is `quite`. It must be
+/// defined before the `@terse` and `@verbose` arms, which are both required, and the macro
+/// still evaluates to an `Option`, because nothing is evaluated in debug builds.
+///
+/// ```no_run
+/// # use::cli_toolbox::release;
+/// # let path = "target/output.txt";
+/// let summary: Option<String> = release! {
+///     @quiet => String::new(),
+///     @terse => format!("created {}", path),
+///     @verbose => format!("created output file {} with default settings", path)
+/// };
+/// ```
+///
+/// _\* in debug builds the expressions are not compiled, so the type of a value has to be known
+/// from where the macro is used, i.e. `let detail: Option<String> = release! { .. }`_
+///
 /// [`Verbosity`]: <https://crates.io/crates/verbosity>
 #[cfg(feature = "release")]
 #[proc_macro]
@@ -246,6 +497,344 @@ pub fn reportln(input: TokenStream) -> TokenStream {
     parse_macro_input!(input as report_macro::ReportLnMacro).into_token_stream().into()
 }
 
+/// Conditionally prints lines to `io::stdout` or `io::stderr` when intended verbosity matches
+/// active verbosity,<br/>while holding the `io::stdout` and `io::stderr` locks once.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `report_block!` macro
+///
+/// Input consists of one or more [`reportln!`] messages, each with its own optional intended
+/// verbosity level, separated by semicolons. Each message is reported independently, in order,
+/// as if it were its own `reportln!`, but the lines are not interleaved with the output of other
+/// threads.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use cli_toolbox::report_block;
+/// # let (files, bytes) = (4, 2048);
+/// report_block! {
+///     @terse "summary";
+///     @verbose "  files: {}", files;
+///     @verbose "  bytes: {}", bytes;
+///     @err-terse "1 file skipped"
+/// }
+/// ```
+///
+/// ## Panics
+///
+/// Just like the [`reportln!`] macro, this also panics if writing to `io::stdout`, `io::stderr`
+/// or an `@to` writer fails.
+///
+/// [`reportln!`]: <macro.reportln.html>
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn report_block(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::ReportBlockMacro).into_token_stream().into()
+}
+
+/// Conditionally writes to `io::stdout` or `io::stderr` when intended verbosity matches
+/// active verbosity,<br/>does not append a new line, evaluates to an `io::Result<()>`.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `try_report!` macro
+///
+/// `try_report!` accepts the same input as the [`report!`] macro, but instead of panicking
+/// it returns any error encountered while writing the output.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use cli_toolbox::try_report;
+/// # fn main() -> std::io::Result<()> {
+/// try_report! {
+///     @terse "some basic message";
+///     @verbose "more verbose message"
+/// }?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## Errors
+///
+/// Returns the `io::Error` encountered writing to `io::stdout`, `io::stderr` or an `@to` writer,
+/// i.e. a closed pipe.
+///
+/// [`report!`]: <macro.report.html>
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn try_report(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::TryReportMacro).into_token_stream().into()
+}
+
+/// Conditionally writes to `io::stdout` or `io::stderr` when intended verbosity matches
+/// active verbosity,<br/>appends a new line, evaluates to an `io::Result<()>`.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `try_reportln!` macro
+///
+/// `try_reportln!` accepts the same input as the [`reportln!`] macro, but instead of panicking
+/// it returns any error encountered while writing the output.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use cli_toolbox::try_reportln;
+/// # fn main() -> std::io::Result<()> {
+/// try_reportln! {
+///     @terse "some basic message";
+///     @verbose "more verbose message"
+/// }?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## Errors
+///
+/// Returns the `io::Error` encountered writing to `io::stdout`, `io::stderr` or an `@to` writer,
+/// i.e. a closed pipe.
+///
+/// [`reportln!`]: <macro.reportln.html>
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn try_reportln(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::TryReportLnMacro).into_token_stream().into()
+}
+
+/// Prints an error to `io::stderr` regardless of active verbosity,<br/>labeled `error:`
+/// and styled `bold` `red`.
+///
+/// Every call is counted, see `cli_toolbox_runtime::error_count`.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `error!` macro
+///
+#[doc = include_str!("docs/semantic_macro_anatomy_doc.md")]
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn error(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::ErrorMacro).into_token_stream().into()
+}
+
+/// Conditionally prints information to `io::stdout` when intended verbosity matches active
+/// verbosity,<br/>labeled `info:`.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `info!` macro
+///
+#[doc = include_str!("docs/semantic_macro_anatomy_doc.md")]
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn info(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::InfoMacro).into_token_stream().into()
+}
+
+/// Conditionally prints a note to `io::stdout` when intended verbosity matches active
+/// verbosity,<br/>labeled `note:` and styled `bold` `cyan`.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `note!` macro
+///
+#[doc = include_str!("docs/semantic_macro_anatomy_doc.md")]
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn note(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::NoteMacro).into_token_stream().into()
+}
+
+/// Conditionally prints a success to `io::stdout` when intended verbosity matches active
+/// verbosity,<br/>labeled `success:` and styled `bold` `green`.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `success!` macro
+///
+#[doc = include_str!("docs/semantic_macro_anatomy_doc.md")]
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn success(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::SuccessMacro).into_token_stream().into()
+}
+
+/// Conditionally prints a warning to `io::stderr` when intended verbosity matches active
+/// verbosity,<br/>labeled `warning:` and styled `bold` `yellow`.
+///
+/// Every call is counted, even if its message is suppressed, see
+/// `cli_toolbox_runtime::warning_count`.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `warn!` macro
+///
+#[doc = include_str!("docs/semantic_macro_anatomy_doc.md")]
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn warn(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::WarnMacro).into_token_stream().into()
+}
+
+/// Conditionally prints to `io::stdout` or `io::stderr` when intended verbosity matches
+/// active verbosity,<br/>appends a new line and returns an error from the enclosing function.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `bail!` macro
+///
+/// Input consists of the error to return, followed by a comma and the same input as the
+/// [`reportln!`] macro. The error is converted with `From::from`, like the `?` operator does.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use cli_toolbox::bail;
+/// # use std::io::{Error, ErrorKind};
+/// # fn main() -> Result<(), Error> {
+/// # let path = "config.toml";
+/// bail! {
+///     Error::from(ErrorKind::NotFound),
+///     @err-terse "{} not found", path;
+///     @err-verbose "{} not found in the current directory", path
+/// }
+/// # }
+/// ```
+///
+/// _\* see [`exit_with!`] to exit the process instead_
+///
+/// ## Panics
+///
+/// Just like the [`reportln!`] macro, this also panics if writing to `io::stdout`, `io::stderr`
+/// or an `@to` writer fails.
+///
+/// [`exit_with!`]: <macro.exit_with.html>
+/// [`reportln!`]: <macro.reportln.html>
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn bail(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::BailMacro).into_token_stream().into()
+}
+
+/// Conditionally prints to `io::stdout` or `io::stderr` when intended verbosity matches
+/// active verbosity,<br/>appends a new line and exits the process with an exit code.
+///
+#[doc = include_str!("docs/report_macro_doc.md")]
+///
+/// ## Anatomy of the `exit_with!` macro
+///
+/// Input consists of the exit code, followed by a comma and the same input as the [`reportln!`]
+/// macro.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use cli_toolbox::exit_with;
+/// # let path = "config.toml";
+/// exit_with! {
+///     2,
+///     @err-terse "{} not found", path;
+///     @err-verbose "{} not found in the current directory", path
+/// }
+/// ```
+///
+/// _\* see [`bail!`] to return an error from the enclosing function instead, which can be tested_
+///
+/// ## Panics
+///
+/// Just like the [`reportln!`] macro, this also panics if writing to `io::stdout`, `io::stderr`
+/// or an `@to` writer fails.
+///
+/// [`bail!`]: <macro.bail.html>
+/// [`reportln!`]: <macro.reportln.html>
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn exit_with(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::ExitWithMacro).into_token_stream().into()
+}
+
+/// Creates the progress of a task, drawn on `io::stdout` according to the active verbosity,
+/// <br/>evaluates to a `cli_toolbox_runtime::Progress`.
+///
+/// ## Anatomy of the `progress!` macro
+///
+/// Input consists of an optional `@level(expr)`, an optional `@multi` or `@total(expr)` and a
+/// message with the same format string and arguments as the [`report!`] macro. The total can be
+/// any integer, a total that is not provided or does not fit a `u64` is unknown.
+///
+/// The progress is hidden when the active verbosity is quite, a spinner when it is terse and
+/// a bar with the rate and the estimated time remaining when it is verbose and the total is
+/// known. Messages of the `report!` macros are written above live progress, so they scroll while
+/// the progress stays at the bottom of the terminal.
+///
+/// When `io::stdout` is not a terminal, progress is written as plain lines at most once per
+/// second instead, `cli_toolbox_runtime::set_progress_output` chooses how progress is written
+/// explicitly. Progress is not written when the output format is json.
+///
+/// ### Example
+///
+/// ```no_run
+/// # use cli_toolbox::{progress, reportln};
+/// # let files = vec!["a.txt", "b.txt"];
+/// let progress = progress! { @total(files.len()) "copying {} files", files.len() };
+///
+/// for file in &files {
+///     reportln! { @verbose "copied {}", file }
+///
+///     progress.inc(1);
+/// }
+///
+/// progress.finish();
+/// ```
+///
+/// ## Concurrent tasks
+///
+/// With `@multi` instead of `@total`, the macro evaluates to a
+/// `cli_toolbox_runtime::MultiProgress` of concurrent tasks. Each task added to it has its own
+/// `Progress`, which can be sent to the thread that runs the task. When verbose, every task is
+/// drawn on its own line above an overall line, when terse the tasks collapse into the overall
+/// line, which summarizes how many of them are done.
+///
+/// ```no_run
+/// # use std::thread;
+/// # use cli_toolbox::{progress, reportln};
+/// # let files = vec![("a.txt", 1024_u64), ("b.txt", 2048)];
+/// let downloads = progress! { @multi "downloading" };
+///
+/// thread::scope(|scope| {
+///     for (file, size) in &files {
+///         let task = downloads.add(Some(*size), file.to_string());
+///
+///         scope.spawn(move || {
+///             // download the file, advancing its task
+///             task.inc(*size);
+///             task.finish();
+///
+///             reportln! { @verbose "downloaded {}", file }
+///         });
+///     }
+/// });
+///
+/// downloads.finish();
+/// ```
+///
+/// ## Panics
+///
+/// Just like the [`reportln!`] macro, this also panics if writing to `io::stdout` fails,
+/// according to the [output policy].
+///
+/// [output policy]: <index.html#output-policy>
+/// [`report!`]: <macro.report.html>
+/// [`reportln!`]: <macro.reportln.html>
+#[cfg(feature = "report")]
+#[proc_macro]
+pub fn progress(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as report_macro::ProgressMacro).into_token_stream().into()
+}
+
 #[cfg(all(debug_assertions, feature = "trace"))]
 fn display<D: ToTokens>(value: &Option<D>) -> String {
     value.as_ref().map_or_else(|| String::from("None"), |val| format!("{}", val.to_token_stream()))
@@ -10,6 +10,8 @@ mod parse;
 mod tokenize;
 
 pub struct Release {
+    level: Option<Expr>,
+    quite: Option<Expr>,
     terse: Option<Expr>,
     verbose: Option<Expr>,
 }
@@ -19,8 +21,8 @@ impl Display for Release {
     //noinspection ALL
     fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
-            fmt, "release! {{\n  terse: {}\n  verbose: {}\n}}",
-            display(&self.terse), display(&self.verbose),
+            fmt, "release! {{\n  level: {}\n  quite: {}\n  terse: {}\n  verbose: {}\n}}",
+            display(&self.level), display(&self.quite), display(&self.terse), display(&self.verbose),
         )
     }
 }
\ No newline at end of file
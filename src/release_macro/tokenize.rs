@@ -1,19 +1,35 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
 
-use crate::common::tokenize::tokenize_expression;
+use crate::common::tokenize::{tokenize_arms, tokenize_evaluated, tokenize_expression, tokenize_some};
 use crate::common::tracing::trace_expansion;
 use crate::release_macro::Release;
 
 impl ToTokens for Release {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(trace_expansion({
-            let expr = tokenize_expression(&self.terse, &self.verbose);
+        let expr = match (&self.quite, &self.terse, &self.verbose) {
+            // release always evaluates to an `Option`, even if there is an arm for every
+            // verbosity, because nothing is evaluated in debug builds
+            (Some(quite), Some(terse), Some(verbose)) =>
+                tokenize_some(&tokenize_arms(self.level.as_ref(), quite, terse, verbose)),
+            _ => tokenize_expression(self.level.as_ref(), self.terse.as_ref(), self.verbose.as_ref())
+        };
 
-            quote! {
+        let evaluated = tokenize_evaluated(&expr);
+        // mixed site hygiene keeps the value from clashing with identifiers in the expressions
+        let value = Ident::new("value", Span::mixed_site());
+
+        // the expressions are only compiled in release builds, in debug builds the value is
+        // inferred from where the macro is used, `None` as a value and `()` as a statement
+        tokens.extend(trace_expansion(quote! {
+            {
                 #[cfg(not(debug_assertions))]
-                #expr
+                let #value = #evaluated;
+                #[cfg(debug_assertions)]
+                let #value = cli_toolbox_runtime::unevaluated(0);
+
+                #value
             }
         }));
     }
-}
\ No newline at end of file
+}
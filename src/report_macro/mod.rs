@@ -3,9 +3,15 @@ use std::fmt::{self, Display, Formatter};
 #[cfg(all(debug_assertions, feature = "trace"))]
 use std::string::ToString;
 
+#[cfg(all(debug_assertions, feature = "trace"))]
+use quote::ToTokens;
+
+use syn::Expr;
 use verbosity::Verbosity;
 
 use crate::common::Message;
+#[cfg(all(debug_assertions, feature = "trace"))]
+use crate::display;
 
 mod parse;
 mod tokenize;
@@ -13,48 +19,207 @@ mod tokenize;
 struct ReportMessage {
     message: Message,
     std_err: bool,
-    verbosity: Verbosity,
+    // `None` reports regardless of the active verbosity
+    verbosity: Option<Verbosity>,
+    writer: Option<Expr>,
 }
 
 #[cfg(all(debug_assertions, feature = "trace"))]
 impl Display for ReportMessage {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        write!(fmt, "{{  message: {},  std_err: {}}}", self.message, self.std_err)
+        write!(
+            fmt, "{{  message: {},  std_err: {},  styled: {},  writer: {}}}",
+            self.message, self.std_err, self.message.style.is_some(), display(&self.writer)
+        )
     }
 }
 
+// clauses of a report macro in the order they are defined, reported according to the active
+// verbosity, which is the level provided with `@level` or otherwise the global verbosity
+//
+// clauses of the same intended verbosity are grouped together and reported in order, a single
+// group is reported at its verbosity or above, always clauses are reported regardless of the
+// active verbosity and can only be elaborated on by verbose clauses, otherwise only the group
+// of the active verbosity is reported
+#[derive(Default)]
+struct ReportMessages {
+    level: Option<Expr>,
+    clauses: Vec<ReportMessage>,
+}
+
 pub struct ReportMacro {
-    terse: Option<ReportMessage>,
-    verbose: Option<ReportMessage>,
+    messages: ReportMessages,
 }
 
 #[cfg(all(debug_assertions, feature = "trace"))]
 impl Display for ReportMacro {
-    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
-        format_report_macro(fmt, self.terse.as_ref(), self.verbose.as_ref(), false)
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        format_report_macro(fmt, "report", &self.messages)
     }
 }
 
 pub struct ReportLnMacro {
-    terse: Option<ReportMessage>,
-    verbose: Option<ReportMessage>,
+    messages: ReportMessages,
 }
 
 #[cfg(all(debug_assertions, feature = "trace"))]
 impl Display for ReportLnMacro {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-        format_report_macro(fmt, self.terse.as_ref(), self.verbose.as_ref(), true)
+        format_report_macro(fmt, "reportln", &self.messages)
+    }
+}
+
+pub struct TryReportMacro {
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for TryReportMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        format_report_macro(fmt, "try_report", &self.messages)
+    }
+}
+
+pub struct TryReportLnMacro {
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for TryReportLnMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        format_report_macro(fmt, "try_reportln", &self.messages)
+    }
+}
+
+pub struct BailMacro {
+    error: Expr,
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for BailMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(fmt, "error: {}", self.error.to_token_stream())?;
+
+        format_report_macro(fmt, "bail", &self.messages)
+    }
+}
+
+pub struct ErrorMacro {
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for ErrorMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        format_report_macro(fmt, "error", &self.messages)
+    }
+}
+
+pub struct ExitWithMacro {
+    code: Expr,
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for ExitWithMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(fmt, "code: {}", self.code.to_token_stream())?;
+
+        format_report_macro(fmt, "exit_with", &self.messages)
+    }
+}
+
+pub struct InfoMacro {
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for InfoMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        format_report_macro(fmt, "info", &self.messages)
+    }
+}
+
+pub struct NoteMacro {
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for NoteMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        format_report_macro(fmt, "note", &self.messages)
+    }
+}
+
+pub struct SuccessMacro {
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for SuccessMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        format_report_macro(fmt, "success", &self.messages)
+    }
+}
+
+pub struct WarnMacro {
+    messages: ReportMessages,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for WarnMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        format_report_macro(fmt, "warn", &self.messages)
+    }
+}
+
+// progress of a task, or of concurrent tasks with `@multi`, drawn according to the active
+// verbosity
+pub struct ProgressMacro {
+    level: Option<Expr>,
+    message: Message,
+    multi: bool,
+    total: Option<Expr>,
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+impl Display for ProgressMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt, "progress! {{\n  level: {}\n  multi: {}\n  total: {}\n  message: {}\n}}",
+            display(&self.level), self.multi, display(&self.total), self.message
+        )
     }
 }
 
+pub struct ReportBlockMacro {
+    level: Option<Expr>,
+    messages: Vec<ReportMessage>,
+}
+
 #[cfg(all(debug_assertions, feature = "trace"))]
-fn format_report_macro(
-    fmt: &mut Formatter, terse: Option<&ReportMessage>, verbose: Option<&ReportMessage>, ln: bool,
-) -> fmt::Result {
-    write!(
-        fmt, "report{}! {{\n  terse: {}\n  verbose: {}\n}}",
-        if ln { "ln" } else { "" },
-        terse.map_or_else(|| "None".to_string(), ToString::to_string),
-        verbose.map_or_else(|| "None".to_string(), ToString::to_string),
-    )
+impl Display for ReportBlockMacro {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(fmt, "report_block! {{\n  level: {}", display(&self.level))?;
+
+        for message in &self.messages {
+            writeln!(fmt, "  {message}")?;
+        }
+
+        write!(fmt, "}}")
+    }
+}
+
+#[cfg(all(debug_assertions, feature = "trace"))]
+fn format_report_macro(fmt: &mut Formatter, name: &str, messages: &ReportMessages) -> fmt::Result {
+    writeln!(fmt, "{name}! {{\n  level: {}", display(&messages.level))?;
+
+    for clause in &messages.clauses {
+        let verbosity = clause.verbosity.as_ref().map_or_else(|| "always".to_string(), ToString::to_string);
+
+        writeln!(fmt, "  {verbosity}: {clause}")?;
+    }
+
+    write!(fmt, "}}")
 }
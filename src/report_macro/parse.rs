@@ -1,15 +1,158 @@
-use syn::Error;
+use syn::{Error, Expr, Ident, Lit, LitByteStr, LitStr, token};
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use verbosity::Verbosity;
 
-use crate::common::{DUPE_VERBOSITY_ERR, kw, QUITE_ERR, VERBOSITY_ORDER_ERR};
+use crate::common::{
+    DUPE_VERBOSITY_ERR, Format, kw, QUITE_ORDER_ERR, Record, Style, VERBOSITY_ORDER_ERR,
+};
+use crate::common::parse::parse_level;
 use crate::common::tracing::{trace_parsed, trace_source};
-use crate::report_macro::{Message, ReportLnMacro, ReportMacro, ReportMessage};
+use crate::report_macro::{
+    BailMacro, ErrorMacro, ExitWithMacro, InfoMacro, Message, NoteMacro, ProgressMacro,
+    ReportBlockMacro, ReportLnMacro, ReportMacro, ReportMessage, ReportMessages, SuccessMacro,
+    TryReportLnMacro, TryReportMacro, WarnMacro,
+};
+
+const ALWAYS_COMBINE_ERR: &str = "only combine always with verbose";
+
+const ALWAYS_ORDER_ERR: &str = "define always before verbose";
+
+const MULTI_TOTAL_ERR: &str =
+    "@total can not be combined with @multi, the total of concurrent tasks is the number of tasks";
+
+const SEMANTIC_STREAM_ERR: &str = "@err- can not be used, the stream is determined by the macro";
+
+const STYLE_ERR: &str =
+    "invalid style, use default, plain, bold, dim, italic, underline, black, red, green, yellow, blue, magenta, cyan or white";
+
+// semantic default styles of `io::stdout` and `io::stderr`, applied with the `default` style
+const STD_OUT_CODES: &[u8] = &[];
+
+const STD_ERR_CODES: &[u8] = &[1, 31];
+
+// stream, default verbosity, label and default style of a semantic report macro
+struct Semantic {
+    codes: &'static [u8],
+    label: &'static str,
+    std_err: bool,
+    verbosity: Option<Verbosity>,
+}
+
+const ERROR: Semantic = Semantic { codes: &[1, 31], label: "error: ", std_err: true, verbosity: None };
+
+const INFO: Semantic = Semantic { codes: &[], label: "info: ", std_err: false, verbosity: Some(Verbosity::Terse) };
+
+const NOTE: Semantic = Semantic { codes: &[1, 36], label: "note: ", std_err: false, verbosity: Some(Verbosity::Verbose) };
+
+const SUCCESS: Semantic = Semantic { codes: &[1, 32], label: "success: ", std_err: false, verbosity: Some(Verbosity::Terse) };
+
+const WARN: Semantic = Semantic { codes: &[1, 33], label: "warning: ", std_err: true, verbosity: Some(Verbosity::Terse) };
+
+impl Parse for BailMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        return trace_parsed(parse(trace_source(input)));
+
+        #[inline]
+        fn parse(input: ParseStream) -> syn::Result<BailMacro> {
+            let error = input.parse()?;
+
+            <Token![,]>::parse(input)?;
+
+            parse_report_macro(input, true, None, |messages| BailMacro { error, messages })
+        }
+    }
+}
+
+impl Parse for ErrorMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        trace_parsed(parse_report_macro(
+            trace_source(input), true, Some(&ERROR), |messages| Self { messages },
+        ))
+    }
+}
+
+impl Parse for ExitWithMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        return trace_parsed(parse(trace_source(input)));
+
+        #[inline]
+        fn parse(input: ParseStream) -> syn::Result<ExitWithMacro> {
+            let code = input.parse()?;
+
+            <Token![,]>::parse(input)?;
+
+            parse_report_macro(input, true, None, |messages| ExitWithMacro { code, messages })
+        }
+    }
+}
+
+impl Parse for InfoMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        trace_parsed(parse_report_macro(
+            trace_source(input), true, Some(&INFO), |messages| Self { messages },
+        ))
+    }
+}
+
+impl Parse for NoteMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        trace_parsed(parse_report_macro(
+            trace_source(input), true, Some(&NOTE), |messages| Self { messages },
+        ))
+    }
+}
+
+impl Parse for ProgressMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        return trace_parsed(parse(trace_source(input)));
+
+        #[inline]
+        fn parse(input: ParseStream) -> syn::Result<ProgressMacro> {
+            let level = parse_level(input)?;
+            let multi = parse_multi(input)?;
+            let span = input.span();
+            let total = parse_total(input)?;
+
+            if multi && total.is_some() {
+                return Err(Error::new(span, MULTI_TOTAL_ERR));
+            }
+
+            let message = Message::parse(input, false)?;
+
+            Ok(ProgressMacro { level, message, multi, total })
+        }
+    }
+}
+
+impl Parse for ReportBlockMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        return trace_parsed(parse(trace_source(input)));
+
+        #[inline]
+        fn parse(input: ParseStream) -> syn::Result<ReportBlockMacro> {
+            let level = parse_level(input)?;
+            let mut messages = Vec::new();
+
+            // every line of a block is reported independently of the other lines
+            loop {
+                let (std_err, verbosity) = parse_verbosity(input, Some(Verbosity::Terse))?;
+                let first = messages.is_empty();
+
+                messages.push(parse_report_message(input, true, std_err, verbosity, None, first)?);
+
+                if input.is_empty() { break; }
+            }
+
+            Ok(ReportBlockMacro { level, messages })
+        }
+    }
+}
 
 impl Parse for ReportLnMacro {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         trace_parsed(parse_report_macro(
-            trace_source(input), true, |terse, verbose| Self { terse, verbose },
+            trace_source(input), true, None, |messages| Self { messages },
         ))
     }
 }
@@ -17,77 +160,195 @@ impl Parse for ReportLnMacro {
 impl Parse for ReportMacro {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         trace_parsed(parse_report_macro(
-            trace_source(input), false, |terse, verbose| Self { terse, verbose },
+            trace_source(input), false, None, |messages| Self { messages },
+        ))
+    }
+}
+
+impl Parse for SuccessMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        trace_parsed(parse_report_macro(
+            trace_source(input), true, Some(&SUCCESS), |messages| Self { messages },
+        ))
+    }
+}
+
+impl Parse for TryReportLnMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        trace_parsed(parse_report_macro(
+            trace_source(input), true, None, |messages| Self { messages },
+        ))
+    }
+}
+
+impl Parse for TryReportMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        trace_parsed(parse_report_macro(
+            trace_source(input), false, None, |messages| Self { messages },
+        ))
+    }
+}
+
+impl Parse for WarnMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        trace_parsed(parse_report_macro(
+            trace_source(input), true, Some(&WARN), |messages| Self { messages },
         ))
     }
 }
 
-#[allow(clippy::shadow_unrelated)] // intention of code is clear
 fn parse_report_macro<T>(
-    input: ParseStream, ln_brk: bool, builder: impl Fn(Option<ReportMessage>, Option<ReportMessage>) -> T,
+    input: ParseStream, ln_brk: bool, semantic: Option<&Semantic>,
+    builder: impl FnOnce(ReportMessages) -> T,
 ) -> syn::Result<T> {
-    let (std_err, verbosity) = parse_verbosity(input)?;
-    let message = ReportMessage {
-        message: Message::parse(input, ln_brk)?,
-        std_err,
-        verbosity,
+    let mut messages = ReportMessages { level: parse_level(input)?, ..ReportMessages::default() };
+    let default = semantic.map_or(Some(Verbosity::Terse), |semantic| semantic.verbosity);
+    loop {
+        let error_span = input.span();
+        let (std_err, verbosity) = parse_verbosity(input, default)?;
+        let std_err = match semantic {
+            Some(_) if std_err => return Err(Error::new(error_span, SEMANTIC_STREAM_ERR)),
+            Some(semantic) => semantic.std_err,
+            None => std_err
+        };
+
+        let defined = |level: Option<Verbosity>|
+            messages.clauses.iter().any(|clause| clause.verbosity == level);
+        let grouped = messages.clauses.last().is_some_and(|clause| clause.verbosity == verbosity);
+
+        // clauses must be defined in order of verbosity, always before any other, clauses of
+        // the same verbosity are grouped together
+        let ordering_err = match verbosity {
+            _ if grouped =>
+                None,
+            level if defined(level) =>
+                Some(DUPE_VERBOSITY_ERR),
+            None if defined(Some(Verbosity::Verbose)) =>
+                Some(ALWAYS_ORDER_ERR),
+            None if defined(Some(Verbosity::Quite)) || defined(Some(Verbosity::Terse)) =>
+                Some(ALWAYS_COMBINE_ERR),
+            Some(Verbosity::Quite | Verbosity::Terse) if defined(None) =>
+                Some(ALWAYS_COMBINE_ERR),
+            Some(Verbosity::Quite)
+                if defined(Some(Verbosity::Terse)) || defined(Some(Verbosity::Verbose)) =>
+                Some(QUITE_ORDER_ERR),
+            Some(Verbosity::Terse) if defined(Some(Verbosity::Verbose)) =>
+                Some(VERBOSITY_ORDER_ERR),
+            _ =>
+                None
+        };
+
+        if let Some(err) = ordering_err {
+            return Err(Error::new(error_span, err));
+        }
+
+        let first = messages.clauses.is_empty();
+
+        messages.clauses.push(
+            parse_report_message(input, ln_brk, std_err, verbosity, semantic, first)?
+        );
+
+        if input.is_empty() { break; }
+    }
+
+    Ok(builder(messages))
+}
+
+fn parse_report_message(
+    input: ParseStream, ln_brk: bool, std_err: bool, verbosity: Option<Verbosity>,
+    semantic: Option<&Semantic>, first: bool,
+) -> syn::Result<ReportMessage> {
+    let mut style = parse_style(input, std_err)?;
+    // the stream of a semantic macro is not designated, so a writer can always replace it
+    let writer = parse_writer(input, std_err && semantic.is_none())?;
+    let level = match verbosity {
+        None => "always",
+        Some(Verbosity::Quite) => "quiet",
+        Some(Verbosity::Terse) => "terse",
+        Some(Verbosity::Verbose) => "verbose"
     };
-    let error_span = input.span();
-
-    match verbosity {
-        Verbosity::Quite =>
-            unreachable!("{}", QUITE_ERR),
-        Verbosity::Terse => {
-            // check if a second message is provided
-            // a second message requires an intended verbosity level
-            let (std_err, verbosity) =
-                if let Ok(parsed_values) = parse_verbosity(input) {
-                    parsed_values
-                } else {
-                    return Ok(builder(Some(message), None));
-                };
-
-            match verbosity {
-                Verbosity::Quite =>
-                    unreachable!("{}", QUITE_ERR),
-                Verbosity::Terse =>
-                    Err(Error::new(error_span, DUPE_VERBOSITY_ERR)),
-                Verbosity::Verbose => {
-                    // only accept a second message that is intended for verbose output
-                    let verbose = ReportMessage {
-                        message: Message::parse(input, ln_brk)?,
-                        std_err,
-                        verbosity,
-                    };
-
-                    Ok(builder(Some(message), Some(verbose)))
-                }
-            }
+    let mut message = Message::parse_unvalidated(input, ln_brk)?;
+
+    // the clause of an invalid format string is only named if there is more than one clause
+    message.validate((!first || !input.is_empty()).then_some(level))?;
+
+    if let Some(semantic) = semantic {
+        message.fmt = prefix_label(&message.fmt, semantic.label);
+
+        if style.is_none() && !semantic.codes.is_empty() {
+            style = Some(Style { codes: semantic.codes.to_vec() });
+        }
+    }
+
+    message.record = Some(Record {
+        level,
+        stream: match (&writer, std_err) {
+            (Some(_), _) => "writer",
+            (None, true) => "stderr",
+            (None, false) => "stdout"
+        },
+    });
+
+    // a `plain` message has no style codes
+    message.style = style.filter(|style| !style.codes.is_empty());
+
+    Ok(ReportMessage { message, std_err, verbosity, writer })
+}
+
+fn parse_style(input: ParseStream, std_err: bool) -> syn::Result<Option<Style>> {
+    if !input.peek(token::Bracket) {
+        return Ok(None);
+    }
+
+    let content;
+
+    bracketed!(content in input);
+
+    let styles = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+    let mut codes = Vec::new();
+
+    // `plain` removes the default style of a semantic macro, so it can not be combined with
+    // other styles
+    if styles.len() == 1 && styles[0] == "plain" {
+        return Ok(Some(Style { codes }));
+    }
+
+    for style in &styles {
+        match style.to_string().as_str() {
+            // semantic default style of the stream
+            "default" => codes.extend(stream_codes(std_err)),
+            "bold" => codes.push(1),
+            "dim" => codes.push(2),
+            "italic" => codes.push(3),
+            "underline" => codes.push(4),
+            "black" => codes.push(30),
+            "red" => codes.push(31),
+            "green" => codes.push(32),
+            "yellow" => codes.push(33),
+            "blue" => codes.push(34),
+            "magenta" => codes.push(35),
+            "cyan" => codes.push(36),
+            "white" => codes.push(37),
+            _ => return Err(Error::new(style.span(), STYLE_ERR))
         }
-        Verbosity::Verbose =>
-            if input.is_empty() {
-                Ok(builder(None, Some(message)))
-            } else {
-                let error_span = input.span();
-
-                match parse_verbosity(input) {
-                    Ok((_, verbosity)) => {
-                        match verbosity {
-                            Verbosity::Quite =>
-                                unreachable!("{}", QUITE_ERR),
-                            Verbosity::Terse =>
-                                Err(Error::new(error_span, VERBOSITY_ORDER_ERR)),
-                            Verbosity::Verbose =>
-                                Err(Error::new(error_span, DUPE_VERBOSITY_ERR))
-                        }
-                    }
-                    Err(err) => Err(err)
-                }
-            }
     }
+
+    if styles.is_empty() {
+        return Err(Error::new(content.span(), STYLE_ERR));
+    }
+
+    Ok(Some(Style { codes }))
+}
+
+const fn stream_codes(std_err: bool) -> &'static [u8] {
+    if std_err { STD_ERR_CODES } else { STD_OUT_CODES }
 }
 
-fn parse_verbosity(input: ParseStream) -> syn::Result<(bool, Verbosity)> {
+// an intended verbosity of `None` reports regardless of the active verbosity, the default
+// verbosity is used when a verbosity is not designated
+fn parse_verbosity(
+    input: ParseStream, default: Option<Verbosity>,
+) -> syn::Result<(bool, Option<Verbosity>)> {
     let mut std_err = false;
     let verbosity;
     let span = input.span();
@@ -102,31 +363,107 @@ fn parse_verbosity(input: ParseStream) -> syn::Result<(bool, Verbosity)> {
             std_err = true;
         }
 
-        if input.peek(kw::terse) {
+        if input.peek(kw::always) {
+            <kw::always>::parse(input)?;
+
+            verbosity = None;
+        } else if input.peek(kw::quiet) {
+            <kw::quiet>::parse(input)?;
+
+            verbosity = Some(Verbosity::Quite);
+        } else if input.peek(kw::quite) {
+            <kw::quite>::parse(input)?;
+
+            verbosity = Some(Verbosity::Quite);
+        } else if input.peek(kw::terse) {
             <kw::terse>::parse(input)?;
 
-            verbosity = Verbosity::Terse;
+            verbosity = Some(Verbosity::Terse);
         } else if input.peek(kw::verbose) {
             <kw::verbose>::parse(input)?;
 
-            verbosity = Verbosity::Verbose;
+            verbosity = Some(Verbosity::Verbose);
         } else {
             return Err(Error::new(
                 span,
-                "invalid verbosity designation, use @terse, @verbose, @err-terse, @err-verbose or leave blank for default level",
+                "invalid verbosity designation, use @always, @quiet, @terse, @verbose, @err-always, @err-quiet, @err-terse, @err-verbose or leave blank for default level",
             ));
         }
     } else if input.is_empty() {
         return Err(Error::new(span, "expecting a string literal or a valid verbosity designation"));
     } else {
-        verbosity = Verbosity::Terse;
+        verbosity = default;
     }
 
     Ok((std_err, verbosity))
 }
 
+fn parse_multi(input: ParseStream) -> syn::Result<bool> {
+    if !(input.peek(Token![@]) && input.peek2(kw::multi)) {
+        return Ok(false);
+    }
+
+    <Token![@]>::parse(input)?;
+    <kw::multi>::parse(input)?;
+
+    Ok(true)
+}
+
+fn parse_total(input: ParseStream) -> syn::Result<Option<Expr>> {
+    if !(input.peek(Token![@]) && input.peek2(kw::total)) {
+        return Ok(None);
+    }
+
+    <Token![@]>::parse(input)?;
+    <kw::total>::parse(input)?;
+
+    let total;
+
+    parenthesized!(total in input);
+
+    Ok(Some(total.parse()?))
+}
+
+fn parse_writer(input: ParseStream, std_err: bool) -> syn::Result<Option<Expr>> {
+    if !(input.peek(Token![@]) && input.peek2(kw::to)) {
+        return Ok(None);
+    }
+
+    let span = input.span();
+
+    <Token![@]>::parse(input)?;
+    <kw::to>::parse(input)?;
+
+    if std_err {
+        return Err(Error::new(span, "@to can not be combined with an @err- verbosity designation"));
+    }
+
+    let writer;
+
+    parenthesized!(writer in input);
+
+    Ok(Some(writer.parse()?))
+}
+
+fn prefix_label(fmt: &Format, label: &str) -> Format {
+    match fmt {
+        Format::Lit(Lit::Str(fmt)) =>
+            Format::Lit(Lit::Str(LitStr::new(&format!("{label}{}", fmt.value()), fmt.span()))),
+        Format::Lit(Lit::ByteStr(fmt)) => Format::Lit(Lit::ByteStr(
+            LitByteStr::new(&[label.as_bytes(), &fmt.value()].concat(), fmt.span())
+        )),
+        Format::Lit(fmt) =>
+            Format::Lit(fmt.clone()),
+        Format::Macro(fmt) =>
+            Format::Macro(parse_quote! { concat!(#label, #fmt) })
+    }
+}
+
 fn verbosity_keyword_peek2(input: ParseStream) -> bool {
-    input.peek2(kw::err) ||
+    input.peek2(kw::always) ||
+        input.peek2(kw::err) ||
+        input.peek2(kw::quiet) ||
+        input.peek2(kw::quite) ||
         input.peek2(kw::terse) ||
         input.peek2(kw::verbose)
 }
@@ -1,50 +1,292 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
+use syn::Expr;
+use verbosity::Verbosity;
 
+use crate::common::{build_json_format_check, is_terminal, json_format_ident};
+use crate::common::tokenize::{tokenize_active_level, tokenize_verbosity_check, tokenize_with_level};
 use crate::common::tracing::trace_expansion;
-use crate::report_macro::{ReportLnMacro, ReportMacro, ReportMessage};
+use crate::report_macro::{
+    BailMacro, ErrorMacro, ExitWithMacro, InfoMacro, NoteMacro, ProgressMacro, ReportBlockMacro,
+    ReportLnMacro, ReportMacro, ReportMessage, ReportMessages, SuccessMacro, TryReportLnMacro,
+    TryReportMacro, WarnMacro,
+};
+
+impl ToTokens for ReportBlockMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(trace_expansion(tokenize_report_block(self.level.as_ref(), &self.messages)));
+    }
+}
+
+impl ToTokens for BailMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let report = tokenize_report_macro(&self.messages, false);
+        let error = &self.error;
+
+        tokens.extend(trace_expansion(quote! {
+            {
+                #report;
+
+                return core::result::Result::Err(core::convert::From::from(#error));
+            }
+        }));
+    }
+}
+
+impl ToTokens for ErrorMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let report = tokenize_report_macro(&self.messages, false);
+
+        tokens.extend(trace_expansion(quote! {
+            {
+                cli_toolbox_runtime::count_error();
+
+                #report
+            }
+        }));
+    }
+}
+
+impl ToTokens for ExitWithMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let report = tokenize_report_macro(&self.messages, false);
+        let code = &self.code;
+
+        tokens.extend(trace_expansion(quote! {
+            {
+                #report;
+
+                std::process::exit(#code)
+            }
+        }));
+    }
+}
+
+impl ToTokens for InfoMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(trace_expansion(tokenize_report_macro(&self.messages, false)));
+    }
+}
+
+impl ToTokens for NoteMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(trace_expansion(tokenize_report_macro(&self.messages, false)));
+    }
+}
+
+impl ToTokens for SuccessMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(trace_expansion(tokenize_report_macro(&self.messages, false)));
+    }
+}
+
+impl ToTokens for WarnMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let report = tokenize_report_macro(&self.messages, false);
+
+        tokens.extend(trace_expansion(quote! {
+            {
+                cli_toolbox_runtime::count_warning();
+
+                #report
+            }
+        }));
+    }
+}
+
+impl ToTokens for ProgressMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let level = self.level.as_ref();
+        let active_level = tokenize_active_level(level);
+        // any integer total is accepted, one that does not fit a `u64` is treated as unknown
+        let total = self.total.as_ref().map_or_else(
+            || quote! { std::option::Option::None },
+            |total| quote! { <u64 as std::convert::TryFrom<_>>::try_from(#total).ok() },
+        );
+        let message = self.message.build_formatted();
+        let progress = if self.multi {
+            quote! { cli_toolbox_runtime::MultiProgress::new(#active_level, #message) }
+        } else {
+            quote! { cli_toolbox_runtime::Progress::new(#active_level, #total, #message) }
+        };
+
+        tokens.extend(trace_expansion(tokenize_with_level(level, progress)));
+    }
+}
 
 impl ToTokens for ReportMacro {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(trace_expansion(tokenize_report_macro(&self.terse, &self.verbose)));
+        tokens.extend(trace_expansion(tokenize_report_macro(&self.messages, false)));
     }
 }
 
 impl ToTokens for ReportLnMacro {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        tokens.extend(trace_expansion(tokenize_report_macro(&self.terse, &self.verbose)));
+        tokens.extend(trace_expansion(tokenize_report_macro(&self.messages, false)));
+    }
+}
+
+impl ToTokens for TryReportMacro {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(trace_expansion(tokenize_report_macro(&self.messages, true)));
     }
 }
 
-impl ToTokens for ReportMessage {
+impl ToTokens for TryReportLnMacro {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let report = self.message.build_message(self.std_err);
-        let is_verbosity = Ident::new(&format!("is_{}", self.verbosity), Span::call_site());
+        tokens.extend(trace_expansion(tokenize_report_macro(&self.messages, true)));
+    }
+}
+
+impl ReportMessage {
+    fn build_report(&self, fallible: bool) -> TokenStream {
+        match &self.writer {
+            Some(writer) =>
+                self.message.build_write(writer, &quote! { false }, fallible),
+            None if fallible => {
+                let stream = if self.std_err { quote! { try_stderr } } else { quote! { try_stdout } };
+
+                self.message.build_write(
+                    &quote! { cli_toolbox_runtime::#stream() }, &is_terminal(self.std_err), fallible,
+                )
+            }
+            None =>
+                self.message.build_message(self.std_err)
+        }
+    }
+
+    fn tokenize(&self, level: Option<&Expr>, fallible: bool) -> TokenStream {
+        self.tokenize_report(&self.build_report(fallible), level, fallible)
+    }
 
-        tokens.extend(quote! { if verbosity::Verbosity::#is_verbosity() { #report; } });
+    fn tokenize_report(
+        &self, report: &TokenStream, level: Option<&Expr>, fallible: bool,
+    ) -> TokenStream {
+        let Some(verbosity) = self.verbosity else {
+            // always reported, regardless of verbosity
+            return if fallible { quote! { #report } } else { quote! { { #report; } } };
+        };
+        let verbosity_check = tokenize_verbosity_check(verbosity, level);
+
+        if fallible {
+            quote! { if #verbosity_check { #report } else { std::io::Result::Ok(()) } }
+        } else {
+            quote! { if #verbosity_check { #report; } }
+        }
     }
 }
 
-fn tokenize_report_macro(
-    terse: &Option<ReportMessage>, verbose: &Option<ReportMessage>,
+fn tokenize_report_block(level: Option<&Expr>, messages: &[ReportMessage]) -> TokenStream {
+    // the output and the streams stay locked while the block is reported, so it is not
+    // interleaved with the output of other threads or redraws of live progress, mixed site
+    // hygiene keeps the locks from clashing with identifiers in the messages
+    let output = Ident::new("_output", Span::mixed_site());
+    let stdout = Ident::new("_stdout", Span::mixed_site());
+    let stderr = Ident::new("_stderr", Span::mixed_site());
+    let mut locks = quote! { let #output = cli_toolbox_runtime::lock_output(); };
+
+    if messages.iter().any(|message| message.writer.is_none() && !message.std_err) {
+        locks.extend(quote! { let #stdout = std::io::stdout().lock(); });
+    }
+
+    if messages.iter().any(|message| message.writer.is_none() && message.std_err) {
+        locks.extend(quote! { let #stderr = std::io::stderr().lock(); });
+    }
+
+    let reports = messages.iter().map(|message| {
+        let report = message.writer.as_ref().map_or_else(
+            || message.message.build_message(message.std_err),
+            |writer| message.message.build_write(writer, &quote! { false }, false),
+        );
+
+        message.tokenize_report(&report, level, false)
+    });
+
+    tokenize_with_level(level, tokenize_with_json_format(&quote! {
+        {
+            #locks
+            #(#reports)*
+        }
+    }))
+}
+
+fn tokenize_report_macro(messages: &ReportMessages, fallible: bool) -> TokenStream {
+    let level = messages.level.as_ref();
+
+    tokenize_with_level(
+        level, tokenize_with_json_format(&tokenize_report_messages(messages, level, fallible)),
+    )
+}
+
+fn tokenize_report_messages(
+    messages: &ReportMessages, level: Option<&Expr>, fallible: bool,
 ) -> TokenStream {
-    match (terse, verbose) {
-        (Some(terse), None) =>
-            quote! { #terse },
-        (None, Some(verbose)) =>
-            quote! { #verbose },
-        (Some(terse), Some(verbose)) => {
-            let terse = terse.message.build_message(terse.std_err);
-            let verbose = verbose.message.build_message(verbose.std_err);
-
-            quote! {
-                match verbosity::Verbosity::level() {
-                    verbosity::Verbosity::Terse => #terse,
-                    verbosity::Verbosity::Verbose => #verbose,
-                    verbosity::Verbosity::Quite => {}
-                }
+    let clauses = &messages.clauses;
+    let verbose = clauses.iter().filter(|clause| clause.verbosity == Some(Verbosity::Verbose)).count();
+    let always = clauses.iter().filter(|clause| clause.verbosity.is_none()).count();
+
+    // a single group, or always clauses elaborated on by verbose clauses, are reported at their
+    // verbosity or above
+    if clauses.iter().all(|clause| clause.verbosity == clauses[0].verbosity) ||
+        always + verbose == clauses.len() {
+        let reports = clauses.iter().map(|clause| clause.tokenize(level, fallible)).collect();
+
+        return tokenize_in_order(reports, fallible);
+    }
+
+    // separate groups are only reported at their specific verbosity level
+    let report = |verbosity: Verbosity| tokenize_in_order(
+        clauses.iter()
+            .filter(|clause| clause.verbosity == Some(verbosity))
+            .map(|clause| clause.build_report(fallible))
+            .collect(),
+        fallible,
+    );
+    let quite = report(Verbosity::Quite);
+    let terse = report(Verbosity::Terse);
+    let verbose = report(Verbosity::Verbose);
+    let active_level = tokenize_active_level(level);
+
+    quote! {
+        match #active_level {
+            verbosity::Verbosity::Quite => #quite,
+            verbosity::Verbosity::Terse => #terse,
+            verbosity::Verbosity::Verbose => #verbose
+        }
+    }
+}
+
+// reports clauses in the order they are defined, a fallible report stops at the first clause
+// that fails and evaluates to its error
+fn tokenize_in_order(mut reports: Vec<TokenStream>, fallible: bool) -> TokenStream {
+    if reports.len() == 1 {
+        return reports.remove(0);
+    }
+
+    if !fallible {
+        return quote! { { #(#reports)* } };
+    }
+
+    reports.into_iter().rev()
+        .reduce(|next, report| quote! {
+            match #report {
+                std::io::Result::Ok(()) => #next,
+                err => err
             }
+        })
+        .unwrap_or_else(|| quote! { std::io::Result::Ok(()) })
+}
+
+// evaluates the output format once, before the messages that are written in that format
+fn tokenize_with_json_format(tokens: &TokenStream) -> TokenStream {
+    let json_format = json_format_ident();
+    let json_format_check = build_json_format_check();
+
+    quote! {
+        {
+            let #json_format = #json_format_check;
+
+            #tokens
         }
-        (None, None) => TokenStream::new()
     }
 }
@@ -0,0 +1,71 @@
+use std::num::ParseIntError;
+
+use test_toolbox::capture;
+use verbosity::Verbosity;
+
+use cli_toolbox::bail;
+
+const EXPECTED_BLANK_STD_ERR: &str = "";
+const EXPECTED_BLANK_STD_OUT: &str = "";
+
+#[derive(Debug, PartialEq)]
+enum AppError {
+    Invalid(u8),
+    Parse(ParseIntError),
+}
+
+impl From<ParseIntError> for AppError {
+    fn from(err: ParseIntError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+#[test]
+fn when_terse_verbosity_should_report_terse_and_return_error() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    fn validate(value: u8) -> Result<u8, AppError> {
+        if value > 42 {
+            bail! {
+                AppError::Invalid(value),
+                @err-terse "invalid value {}", value;
+                @err-verbose "invalid value {}, expected 42 or less", value
+            }
+        }
+
+        Ok(value)
+    }
+
+    let expected = "invalid value 84\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        assert_eq!(Err(AppError::Invalid(84)), validate(84));
+    }};
+
+    assert_eq!(expected, actual_std_err);
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+}
+
+#[test]
+fn when_terse_verbosity_should_convert_returned_error() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    fn parse(value: &str) -> Result<u8, AppError> {
+        match value.parse::<u8>() {
+            Ok(value) => Ok(value),
+            Err(err) => bail! { err, @err-verbose "could not parse {}", value }
+        }
+    }
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        assert_eq!(Ok(42), parse("42"));
+        assert!(matches!(parse("forty two"), Err(AppError::Parse(_))));
+    }};
+
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+}
@@ -0,0 +1,13 @@
+use cli_toolbox::{debug, debugln};
+
+fn main() {
+    debug! { @quiet "quiet debugging output: {}", 42 }
+
+    debugln! { @always "always debugging output: {}", 42 }
+
+    debug! { @err-terse validate_some_important_such_and_such(); }
+
+    debug! { @loc validate_some_important_such_and_such(); }
+}
+
+fn validate_some_important_such_and_such() {}
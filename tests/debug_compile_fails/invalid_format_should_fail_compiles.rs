@@ -0,0 +1,9 @@
+use cli_toolbox::{debug, debugln};
+
+fn main() {
+    debug! { "missing arguments: {} {:.*}", 42 }
+
+    debugln! { @err "unused arguments: {answer}", 42, answer = 42 }
+
+    debugln! { @terse "invalid spec: {:>width$.q}", 42, width = 4 }
+}
@@ -1,3 +1,5 @@
+use std::ffi::OsStr;
+
 use test_toolbox::{capture, expect};
 
 use cli_toolbox::debug;
@@ -294,3 +296,90 @@ fn when_message_with_unsafe_arg_should_output() {
     #[cfg(debug_assertions)]
     unsafe fn unsafe_method_to_call() -> usize { 42 }
 }
+
+#[test]
+fn when_message_with_captured_arg_should_output() {
+    expect! { expected_stdout = "", "DBG: debugging output: 42" }
+
+    #[cfg(debug_assertions)]
+        let answer = 42;
+
+    let (actual_stdout, actual_stderr) = capture! {
+        debug! { "DBG: debugging output: {answer}" }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_named_arg_should_output() {
+    expect! { expected_stdout = "", "DBG: debugging output: 42" }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        debug! { "DBG: debugging output: {answer}", answer = 42 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_trailing_comma_should_output() {
+    expect! { expected_stdout = "", "DBG: debugging output: 42" }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        debug! { "DBG: debugging output: {}", 42, }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_width_and_precision_args_should_output() {
+    expect! { expected_stdout = "", "DBG: debugging output:   42.00" }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        debug! { "DBG: debugging output: {:>width$.precision$}", 42.0, width = 7, precision = 2 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_concat_format_should_output() {
+    expect! { expected_stdout = "", "DBG: debugging output: 42" }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        debug! { concat!("DBG: ", "debugging output: {}"), 42 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_prebuilt_args_should_output() {
+    expect! { expected_stdout = "", "DBG: debugging output: 42" }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        debug! { args: format_args!("DBG: debugging output: {}", 42) }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_byte_string_message_with_byte_args_should_output() {
+    expect! { expected_stdout = "", "DBG: debugging output: 42 {42}" }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        debug! { b"DBG: debugging output: {} {{{}}}", OsStr::new("42"), b"42" }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
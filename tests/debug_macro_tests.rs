@@ -37,3 +37,49 @@ fn when_debugging_should_output_debugging_information_line_only_when_unoptimized
     assert_eq!(expected_stdout, actual_stdout);
     assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
 }
+
+#[test]
+fn when_debugging_should_output_debugging_information_to_std_err_only_when_unoptimized() {
+    expect! { expected_stderr = "", "DBG: invaluable debugging output: 42\n" }
+
+    let (actual_stdout, actual_stderr) = capture! {
+        debugln! { @err "DBG: invaluable debugging output: {}", 42 }
+    };
+
+    assert_eq!("", actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
+
+#[test]
+fn when_debugging_should_output_debugging_information_location_only_when_unoptimized() {
+    let (actual_stdout, actual_stderr) = capture! {
+        debugln! { @loc "DBG: invaluable debugging output: {}", 42 }
+    };
+    let line = line!() - 2;
+
+    expect! {
+        expected_stdout = String::new(),
+        format!("[{}:{line} {}] DBG: invaluable debugging output: 42\n", file!(), module_path!())
+    }
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_debugging_should_output_debugging_value_only_when_unoptimized() {
+    let (actual_stdout, actual_stderr) = capture! {{
+        let actual_value = debug! { =usize::pow(2, 5) + 10 };
+
+        assert_eq!(42, actual_value);
+    }};
+    let line = line!() - 4;
+
+    expect! {
+        expected_stderr = String::new(),
+        format!("[{}:{line} {}] usize :: pow(2, 5) + 10 = 42\n", file!(), module_path!())
+    }
+
+    assert_eq!("", actual_stdout);
+    assert_eq!(expected_stderr, actual_stderr);
+}
@@ -0,0 +1,92 @@
+#![allow(unused_mut)] // for testing purposes
+
+use test_toolbox::{capture, expect};
+use verbosity::Verbosity;
+
+use cli_toolbox::{debug, debugln};
+
+const EXPECTED_BLANK_STD_ERR: &str = "";
+const EXPECTED_BLANK_STD_OUT: &str = "";
+
+#[test]
+fn when_terse_verbosity_should_evaluate_terse_expression_only_when_unoptimized() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    expect! { expected = usize::default(), 42 }
+    let mut actual_value = usize::default();
+
+    debug! { @terse actual_value = 42 }
+
+    assert_eq!(expected, actual_value);
+}
+
+#[test]
+fn when_terse_verbosity_should_not_evaluate_verbose_expression() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected = usize::default();
+
+    let mut actual_value = usize::default();
+
+    debug! { @verbose actual_value = 42 }
+
+    assert_eq!(expected, actual_value);
+}
+
+#[test]
+fn when_terse_verbosity_should_output_terse_debugging_information_only_when_unoptimized() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    expect! { expected_stdout = "", "DBG: terse debugging output: 42" }
+
+    let (actual_stdout, actual_stderr) = capture! {{
+        debug! { @terse "DBG: terse debugging output: {}", 42 }
+        debug! { @verbose "DBG: verbose debugging output: {}", 42 }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "debug!");
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr, "debug!");
+
+    expect! { expected_stdout = "", "DBG: terse debugging output: 42\n" }
+
+    let (actual_stdout, actual_stderr) = capture! {{
+        debugln! { @terse "DBG: terse debugging output: {}", 42 }
+        debugln! { @verbose "DBG: verbose debugging output: {}", 42 }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "debugln!");
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr, "debugln!");
+}
+
+#[test]
+fn when_terse_verbosity_should_output_err_terse_debugging_information_only_when_unoptimized() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    expect! { expected_stderr = "", "DBG: terse debugging output: 42" }
+
+    let (actual_stdout, actual_stderr) = capture! {{
+        debug! { @err-terse "DBG: terse debugging output: {}", 42 }
+        debug! { @err-verbose "DBG: verbose debugging output: {}", 42 }
+    }};
+
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout, "debug!");
+    assert_eq!(expected_stderr, actual_stderr, "debug!");
+
+    expect! { expected_stderr = "", "DBG: terse debugging output: 42\n" }
+
+    let (actual_stdout, actual_stderr) = capture! {{
+        debugln! { @err-terse "DBG: terse debugging output: {}", 42 }
+        debugln! { @err-verbose "DBG: verbose debugging output: {}", 42 }
+    }};
+
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout, "debugln!");
+    assert_eq!(expected_stderr, actual_stderr, "debugln!");
+}
@@ -0,0 +1,18 @@
+use cli_toolbox::eval;
+
+fn main() {
+    let _ = eval! {
+        @verbose => 42,
+        @terse => 21
+    };
+
+    let _ = eval! {
+        @terse => 21,
+        @terse => 42
+    };
+
+    let _ = eval! {
+        @terse => 21,
+        println!("verbose message: {}", 42)
+    };
+}
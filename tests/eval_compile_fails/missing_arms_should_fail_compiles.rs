@@ -0,0 +1,21 @@
+use cli_toolbox::eval;
+
+fn main() {
+    let _ = eval! {
+        @quiet => 0,
+        @verbose => 42
+    };
+
+    let _ = eval! { @quiet => 0 };
+
+    let _ = eval! {
+        @terse => 21,
+        @quiet => 0,
+        @verbose => 42
+    };
+
+    let _ = eval! {
+        @terse => 21,
+        @verbose => 42
+    };
+}
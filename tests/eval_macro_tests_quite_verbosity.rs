@@ -54,3 +54,40 @@ fn when_quite_verbosity_should_suppress_terse_verbose() {
 
     assert_eq!(expected, actual)
 }
+
+#[test]
+fn when_quite_verbosity_should_evaluate_value() {
+    Verbosity::Quite.set_as_global();
+
+    assert_eq!(None, eval! { @terse "terse" });
+    assert_eq!(None, eval! { @verbose 21 * 2 });
+
+    let actual = eval! {
+        @terse 21
+        @verbose {
+            let value = 21;
+
+            value * 2
+        }
+    };
+
+    assert_eq!(None, actual);
+}
+
+#[test]
+#[allow(clippy::needless_late_init)] // the exhaustive arms initialize the value
+fn when_quite_verbosity_should_evaluate_quite_arm() {
+    Verbosity::Quite.set_as_global();
+
+    let expected = "quiet";
+    let actual;
+
+    eval! {
+        @quiet => actual = "quiet",
+        @terse => actual = "terse",
+        @verbose => actual = "verbose"
+    }
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected, eval! { @quite => "quiet", @terse => "terse", @verbose => "verbose" });
+}
@@ -54,3 +54,40 @@ fn when_terse_verbosity_should_evaluate_terse_suppress_verbose() {
 
     assert_eq!(expected, actual)
 }
+
+#[test]
+fn when_terse_verbosity_should_evaluate_value() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Some("terse"), eval! { @terse "terse" });
+    assert_eq!(None, eval! { @verbose 21 * 2 });
+
+    let actual = eval! {
+        @terse 21
+        @verbose {
+            let value = 21;
+
+            value * 2
+        }
+    };
+
+    assert_eq!(Some(21), actual);
+}
+
+#[test]
+#[allow(clippy::needless_late_init)] // the exhaustive arms initialize the value
+fn when_terse_verbosity_should_evaluate_terse_arm() {
+    Verbosity::Terse.set_as_global();
+
+    let expected = "terse";
+    let actual;
+
+    eval! {
+        @quiet => actual = "quiet",
+        @terse => actual = "terse",
+        @verbose => actual = "verbose"
+    }
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected, eval! { @quite => "quiet", @terse => "terse", @verbose => "verbose" });
+}
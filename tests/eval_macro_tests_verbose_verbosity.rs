@@ -54,3 +54,40 @@ fn when_verbose_verbosity_should_suppress_terse_evaluate_verbose() {
 
     assert_eq!(expected, actual)
 }
+
+#[test]
+fn when_verbose_verbosity_should_evaluate_value() {
+    Verbosity::Verbose.set_as_global();
+
+    assert_eq!(Some("terse"), eval! { @terse "terse" });
+    assert_eq!(Some(42), eval! { @verbose 21 * 2 });
+
+    let actual = eval! {
+        @terse 21
+        @verbose {
+            let value = 21;
+
+            value * 2
+        }
+    };
+
+    assert_eq!(Some(42), actual);
+}
+
+#[test]
+#[allow(clippy::needless_late_init)] // the exhaustive arms initialize the value
+fn when_verbose_verbosity_should_evaluate_verbose_arm() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected = "verbose";
+    let actual;
+
+    eval! {
+        @quiet => actual = "quiet",
+        @terse => actual = "terse",
+        @verbose => actual = "verbose"
+    }
+
+    assert_eq!(expected, actual);
+    assert_eq!(expected, eval! { @quite => "quiet", @terse => "terse", @verbose => "verbose" });
+}
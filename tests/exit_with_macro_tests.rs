@@ -0,0 +1,74 @@
+//! runs without the test harness, so a child copy of this test executable can report and exit
+//! without ending the tests, the exit code and output of the child are asserted instead
+
+use std::env;
+use std::process::{Command, Output};
+
+use cli_toolbox_runtime::{ColorChoice, set_color_choice};
+use verbosity::Verbosity;
+
+use cli_toolbox::exit_with;
+
+const CHILD_LEVEL_VAR: &str = "CLI_TOOLBOX_TEST_CHILD_LEVEL";
+const EXIT_CODE: i32 = 2;
+
+fn main() {
+    if let Ok(child_level) = env::var(CHILD_LEVEL_VAR) {
+        exit_with_report(&child_level);
+    }
+
+    when_quite_should_exit_without_reporting();
+    when_terse_should_report_terse_message_and_exit();
+    when_verbose_should_report_verbose_message_and_exit();
+}
+
+fn when_quite_should_exit_without_reporting() {
+    let output = run_child("quite");
+
+    assert_eq!(Some(EXIT_CODE), output.status.code());
+    assert_eq!("", String::from_utf8_lossy(&output.stdout));
+    assert_eq!("", String::from_utf8_lossy(&output.stderr));
+}
+
+fn when_terse_should_report_terse_message_and_exit() {
+    let output = run_child("terse");
+
+    assert_eq!(Some(EXIT_CODE), output.status.code());
+    assert_eq!("", String::from_utf8_lossy(&output.stdout));
+    assert_eq!("config.toml not found\n", String::from_utf8_lossy(&output.stderr));
+}
+
+fn when_verbose_should_report_verbose_message_and_exit() {
+    let output = run_child("verbose");
+
+    assert_eq!(Some(EXIT_CODE), output.status.code());
+    assert_eq!("", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(
+        "config.toml not found in the current directory\n", String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn run_child(child_level: &str) -> Output {
+    Command::new(env::current_exe().expect("test executable"))
+        .env(CHILD_LEVEL_VAR, child_level)
+        .output()
+        .expect("child test executable output")
+}
+
+fn exit_with_report(child_level: &str) -> ! {
+    match child_level {
+        "quite" => Verbosity::Quite.set_as_global(),
+        "verbose" => Verbosity::Verbose.set_as_global(),
+        _ => Verbosity::Terse.set_as_global()
+    }
+
+    set_color_choice(ColorChoice::Never);
+
+    let path = "config.toml";
+
+    exit_with! {
+        EXIT_CODE,
+        @err-terse "{} not found", path;
+        @err-verbose "{} not found in the current directory", path
+    }
+}
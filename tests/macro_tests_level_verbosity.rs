@@ -0,0 +1,77 @@
+#![allow(unused_mut)] // for testing purposes
+
+use test_toolbox::capture;
+use verbosity::Verbosity;
+
+use cli_toolbox::{eval, release, reportln};
+
+const EXPECTED_BLANK_STD_ERR: &str = "";
+
+struct Job {
+    verbosity: Verbosity,
+}
+
+#[test]
+fn when_level_provided_should_eval_by_provided_level_not_global() {
+    let expectations = [(Verbosity::Quite, 0), (Verbosity::Terse, 21), (Verbosity::Verbose, 42)];
+
+    for (level, expected) in expectations {
+        let job = Job { verbosity: level };
+        let mut actual = 0;
+
+        eval! {
+            @level(job.verbosity)
+            @terse actual = 21;
+            @verbose actual = 42
+        }
+
+        assert_eq!(expected, actual, "{level}");
+    }
+}
+
+#[test]
+fn when_level_provided_should_release_by_provided_level_not_global() {
+    let expectations = [(Verbosity::Quite, 0), (Verbosity::Terse, 21), (Verbosity::Verbose, 42)];
+
+    for (level, release_expected) in expectations {
+        // release! is only evaluated in optimized builds
+        let expected = if cfg!(debug_assertions) { 0 } else { release_expected };
+
+        let mut actual = 0;
+
+        release! {
+            @level(level)
+            @terse actual = 21;
+            @verbose actual = 42
+        }
+
+        assert_eq!(expected, actual, "{level}");
+    }
+}
+
+#[test]
+fn when_level_provided_should_report_by_provided_level_not_global() {
+    let expectations = [
+        (Verbosity::Quite, "quiet message\n"),
+        (Verbosity::Terse, "terse message\nterse message\n"),
+        (Verbosity::Verbose, "terse message\nverbose message\nverbose message\n"),
+    ];
+
+    for (level, expected) in expectations {
+        let job = Job { verbosity: level };
+
+        let (actual_stdout, actual_std_err) = capture! {{
+            reportln! { @level(job.verbosity) "terse message" }
+            reportln! { @level(job.verbosity) @verbose "verbose message" }
+            reportln! {
+                @level(job.verbosity)
+                @quiet "quiet message";
+                @terse "terse message";
+                @verbose "verbose message"
+            }
+        }};
+
+        assert_eq!(expected, actual_stdout, "{level}");
+        assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err, "{level}");
+    }
+}
@@ -0,0 +1,128 @@
+//! runs without the test harness, so the verbosity filter is set once, before any macro reads it
+//! and without other tests changing the environment in parallel
+
+#![allow(unused_mut)] // for testing purposes
+
+use std::env;
+
+use test_toolbox::expect;
+use verbosity::Verbosity;
+
+use cli_toolbox::{eval, release, reportln};
+
+fn main() {
+    Verbosity::Terse.set_as_global();
+
+    env::set_var(
+        "CLI_VERBOSITY",
+        "macro_tests_module_verbosity::net=verbose, macro_tests_module_verbosity::db=quiet",
+    );
+
+    net::when_module_filtered_verbose_should_eval_verbose();
+    net::when_module_filtered_verbose_should_release_verbose();
+    net::when_module_filtered_verbose_should_report_verbose();
+    net::sub_module::when_parent_module_filtered_verbose_should_report_verbose();
+    network::when_module_path_only_shares_prefix_should_not_be_filtered();
+    db::when_module_filtered_quiet_should_not_eval_terse();
+    db::when_module_filtered_quiet_should_report_quiet();
+    when_module_not_filtered_should_use_global_verbosity();
+}
+
+mod net {
+    use super::*;
+
+    pub fn when_module_filtered_verbose_should_eval_verbose() {
+        let mut actual = 0;
+
+        eval! { @verbose actual = 42 }
+
+        assert_eq!(42, actual);
+    }
+
+    pub fn when_module_filtered_verbose_should_release_verbose() {
+        expect! { expected = 42, 0 }
+
+        let mut actual = 0;
+
+        release! { @verbose actual = 42 }
+
+        assert_eq!(expected, actual);
+    }
+
+    pub fn when_module_filtered_verbose_should_report_verbose() {
+        let expected = "terse message\nverbose message\n";
+
+        let mut actual = Vec::new();
+
+        reportln! { @to(&mut actual) "terse message" }
+        reportln! { @verbose @to(&mut actual) "verbose message" }
+
+        assert_eq!(expected, String::from_utf8_lossy(&actual));
+    }
+
+    pub mod sub_module {
+        use super::super::*;
+
+        pub fn when_parent_module_filtered_verbose_should_report_verbose() {
+            let expected = "verbose message\n";
+
+            let mut actual = Vec::new();
+
+            reportln! { @verbose @to(&mut actual) "verbose message" }
+
+            assert_eq!(expected, String::from_utf8_lossy(&actual));
+        }
+    }
+}
+
+mod network {
+    use super::*;
+
+    pub fn when_module_path_only_shares_prefix_should_not_be_filtered() {
+        let expected = "terse message\n";
+
+        let mut actual = Vec::new();
+
+        reportln! { @to(&mut actual) "terse message" }
+        reportln! { @verbose @to(&mut actual) "verbose message" }
+
+        assert_eq!(expected, String::from_utf8_lossy(&actual));
+    }
+}
+
+mod db {
+    use super::*;
+
+    pub fn when_module_filtered_quiet_should_not_eval_terse() {
+        let mut actual = 0;
+
+        eval! { actual = 42 }
+
+        assert_eq!(0, actual);
+    }
+
+    pub fn when_module_filtered_quiet_should_report_quiet() {
+        let expected = "quiet message\n";
+
+        let mut actual = Vec::new();
+
+        reportln! {
+            @quiet @to(&mut actual) "quiet message";
+            @terse @to(&mut actual) "terse message";
+            @verbose @to(&mut actual) "verbose message"
+        }
+
+        assert_eq!(expected, String::from_utf8_lossy(&actual));
+    }
+}
+
+fn when_module_not_filtered_should_use_global_verbosity() {
+    let expected = "terse message\n";
+
+    let mut actual = Vec::new();
+
+    reportln! { @to(&mut actual) "terse message" }
+    reportln! { @verbose @to(&mut actual) "verbose message" }
+
+    assert_eq!(expected, String::from_utf8_lossy(&actual));
+}
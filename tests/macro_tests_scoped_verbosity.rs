@@ -0,0 +1,147 @@
+#![allow(unused_mut)] // for testing purposes
+
+use std::thread;
+
+use cli_toolbox_runtime::{scoped_verbosity, VerbosityGuard, with_verbosity};
+use verbosity::Verbosity;
+
+use cli_toolbox::{eval, release, reportln};
+
+// every test sets the same global verbosity, which the scoped verbosity overrides
+fn set_global_verbosity() {
+    Verbosity::Terse.set_as_global();
+}
+
+#[test]
+fn when_scoped_verbosity_should_eval_by_scoped_level_not_global() {
+    set_global_verbosity();
+
+    let expectations = [(Verbosity::Quite, 0), (Verbosity::Terse, 21), (Verbosity::Verbose, 42)];
+
+    for (level, expected) in expectations {
+        let mut actual = 0;
+
+        with_verbosity(level, || {
+            eval! {
+                @terse actual = 21;
+                @verbose actual = 42
+            }
+        });
+
+        assert_eq!(expected, actual, "{level}");
+    }
+}
+
+#[test]
+fn when_scoped_verbosity_should_release_by_scoped_level_not_global() {
+    set_global_verbosity();
+
+    let expectations = [(Verbosity::Quite, 0), (Verbosity::Terse, 21), (Verbosity::Verbose, 42)];
+
+    for (level, release_expected) in expectations {
+        // release! is only evaluated in optimized builds
+        let expected = if cfg!(debug_assertions) { 0 } else { release_expected };
+
+        let mut actual = 0;
+
+        with_verbosity(level, || {
+            release! {
+                @terse actual = 21;
+                @verbose actual = 42
+            }
+        });
+
+        assert_eq!(expected, actual, "{level}");
+    }
+}
+
+#[test]
+fn when_scoped_verbosity_should_report_by_scoped_level_not_global() {
+    set_global_verbosity();
+
+    let expectations = [
+        (Verbosity::Quite, "quiet message\n"),
+        (Verbosity::Terse, "terse message\nterse message\n"),
+        (Verbosity::Verbose, "terse message\nverbose message\nverbose message\n"),
+    ];
+
+    for (level, expected) in expectations {
+        let mut actual = Vec::new();
+
+        with_verbosity(level, || {
+            reportln! { @quiet @to(&mut actual) "quiet message" }
+            reportln! { @terse @to(&mut actual) "terse message" }
+            reportln! { @verbose @to(&mut actual) "verbose message" }
+            reportln! {
+                @terse @to(&mut actual) "terse message";
+                @verbose @to(&mut actual) "verbose message"
+            }
+        });
+
+        assert_eq!(expected, String::from_utf8_lossy(&actual), "{level}");
+    }
+}
+
+#[test]
+fn when_level_provided_should_report_by_provided_level_not_scoped() {
+    set_global_verbosity();
+
+    let mut actual = Vec::new();
+
+    with_verbosity(Verbosity::Quite, || {
+        reportln! { @level(Verbosity::Verbose) @verbose @to(&mut actual) "verbose message" }
+    });
+
+    assert_eq!("verbose message\n", String::from_utf8_lossy(&actual));
+}
+
+#[test]
+fn when_guard_dropped_should_restore_previous_scoped_verbosity() {
+    set_global_verbosity();
+
+    let mut actual = Vec::new();
+
+    {
+        let _verbose = VerbosityGuard::new(Verbosity::Verbose);
+
+        {
+            let _quite = VerbosityGuard::new(Verbosity::Quite);
+
+            reportln! { @to(&mut actual) "suppressed message" }
+
+            assert_eq!(Some(Verbosity::Quite), scoped_verbosity());
+        }
+
+        reportln! { @verbose @to(&mut actual) "verbose message" }
+
+        assert_eq!(Some(Verbosity::Verbose), scoped_verbosity());
+    }
+
+    reportln! { @verbose @to(&mut actual) "suppressed message" }
+
+    assert_eq!(None, scoped_verbosity());
+    assert_eq!("verbose message\n", String::from_utf8_lossy(&actual));
+}
+
+#[test]
+fn when_scoped_verbosity_should_only_apply_to_current_thread() {
+    set_global_verbosity();
+
+    let jobs = [Verbosity::Quite, Verbosity::Terse, Verbosity::Verbose].map(|level| {
+        thread::spawn(move || with_verbosity(level, || {
+            let mut actual = Vec::new();
+
+            reportln! {
+                @terse @to(&mut actual) "terse job";
+                @verbose @to(&mut actual) "verbose job"
+            }
+
+            String::from_utf8(actual).expect("utf8 output")
+        }))
+    });
+
+    let actual = jobs.map(|job| job.join().expect("job"));
+
+    assert_eq!(["", "terse job\n", "verbose job\n"], actual);
+    assert_eq!(None, scoped_verbosity());
+}
@@ -0,0 +1,141 @@
+//! runs without the test harness, so a child copy of this test executable can write to a pipe
+//! whose read end has already been closed, without the harness writing to it first
+
+use std::env;
+use std::process::{Command, Output, Stdio};
+
+use cli_toolbox_runtime::{OutputPolicy, set_output_policy};
+use verbosity::Verbosity;
+
+use cli_toolbox::{debugln, reportln, try_reportln};
+
+const CHILD_MACRO_VAR: &str = "CLI_TOOLBOX_TEST_CHILD_MACRO";
+const CHILD_SET_IGNORE_VAR: &str = "CLI_TOOLBOX_TEST_CHILD_SET_IGNORE";
+const OUTPUT_POLICY_VAR: &str = "CLI_TOOLBOX_OUTPUT_POLICY";
+const LINES_WRITTEN: usize = 10_000;
+
+fn main() {
+    if let Ok(child_macro) = env::var(CHILD_MACRO_VAR) {
+        write_to_closed_pipe(&child_macro);
+
+        return;
+    }
+
+    // debug output is only compiled into unoptimized builds
+    let child_macros: &[&str] = if cfg!(debug_assertions) { &["debug", "report"] } else { &["report"] };
+
+    for child_macro in child_macros {
+        when_exit_policy_should_exit_quietly_with_sigpipe_code(child_macro);
+        when_ignore_policy_should_continue_quietly(child_macro);
+        when_panic_policy_should_panic(child_macro);
+        when_no_policy_should_panic(child_macro);
+        when_policy_set_at_runtime_should_override_environment(child_macro);
+    }
+
+    when_try_report_should_return_error_regardless_of_policy();
+}
+
+fn when_exit_policy_should_exit_quietly_with_sigpipe_code(child_macro: &str) {
+    let output = run_child_with_closed_stdout(child_macro, Some("exit"));
+
+    assert_eq!(Some(141), output.status.code(), "{child_macro}");
+    assert_eq!("", String::from_utf8_lossy(&output.stderr), "{child_macro}");
+}
+
+fn when_ignore_policy_should_continue_quietly(child_macro: &str) {
+    let output = run_child_with_closed_stdout(child_macro, Some("ignore"));
+
+    assert_eq!(Some(0), output.status.code(), "{child_macro}");
+    assert_eq!(
+        format!("{LINES_WRITTEN} lines written\n"), String::from_utf8_lossy(&output.stderr),
+        "{child_macro}"
+    );
+}
+
+fn when_panic_policy_should_panic(child_macro: &str) {
+    let output = run_child_with_closed_stdout(child_macro, Some("panic"));
+
+    assert_eq!(Some(101), output.status.code(), "{child_macro}");
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("failed printing to stdout"),
+        "{child_macro}"
+    );
+}
+
+fn when_no_policy_should_panic(child_macro: &str) {
+    let output = run_child_with_closed_stdout(child_macro, None);
+
+    assert_eq!(Some(101), output.status.code(), "{child_macro}");
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("failed printing to stdout"),
+        "{child_macro}"
+    );
+}
+
+fn when_policy_set_at_runtime_should_override_environment(child_macro: &str) {
+    let output = run_child_with_closed_stdout_env(child_macro, Some("panic"), &[(CHILD_SET_IGNORE_VAR, "1")]);
+
+    assert_eq!(Some(0), output.status.code(), "{child_macro}");
+    assert_eq!(
+        format!("{LINES_WRITTEN} lines written\n"), String::from_utf8_lossy(&output.stderr),
+        "{child_macro}"
+    );
+}
+
+fn when_try_report_should_return_error_regardless_of_policy() {
+    for policy in [Some("exit"), Some("ignore"), Some("panic"), None] {
+        let output = run_child_with_closed_stdout("try_report", policy);
+
+        assert_eq!(Some(0), output.status.code(), "{policy:?}");
+        assert_eq!("BrokenPipe\n", String::from_utf8_lossy(&output.stderr), "{policy:?}");
+    }
+}
+
+fn run_child_with_closed_stdout(child_macro: &str, policy: Option<&str>) -> Output {
+    run_child_with_closed_stdout_env(child_macro, policy, &[])
+}
+
+fn run_child_with_closed_stdout_env(
+    child_macro: &str, policy: Option<&str>, vars: &[(&str, &str)],
+) -> Output {
+    let mut command = Command::new(env::current_exe().expect("test executable"));
+
+    command.env(CHILD_MACRO_VAR, child_macro)
+        .env_remove(OUTPUT_POLICY_VAR)
+        .envs(vars.iter().copied())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    if let Some(policy) = policy {
+        command.env(OUTPUT_POLICY_VAR, policy);
+    }
+
+    let mut child = command.spawn().expect("child test executable");
+
+    // close the read end of the pipe before the child writes to it
+    drop(child.stdout.take());
+
+    child.wait_with_output().expect("child test executable output")
+}
+
+fn write_to_closed_pipe(child_macro: &str) {
+    Verbosity::Terse.set_as_global();
+
+    if env::var_os(CHILD_SET_IGNORE_VAR).is_some() {
+        set_output_policy(OutputPolicy::Ignore);
+    }
+
+    for line in 0..LINES_WRITTEN {
+        match child_macro {
+            "debug" => { debugln! { "debug line {}", line } }
+            "try_report" => if let Err(err) = try_reportln! { "report line {}", line } {
+                eprintln!("{:?}", err.kind());
+
+                return;
+            }
+            _ => { reportln! { "report line {}", line } }
+        }
+    }
+
+    eprintln!("{LINES_WRITTEN} lines written");
+}
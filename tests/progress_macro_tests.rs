@@ -0,0 +1,178 @@
+//! runs without the test harness, so the progress output is set by each test without other tests
+//! changing it in parallel, live progress is drawn even though the captured output is not a terminal
+
+use std::thread;
+
+use cli_toolbox_runtime::{ColorChoice, ProgressOutput, set_color_choice, set_progress_output};
+use test_toolbox::capture;
+use verbosity::Verbosity;
+
+use cli_toolbox::{progress, reportln};
+
+fn main() {
+    Verbosity::Terse.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    when_quite_should_hide_progress();
+    when_terse_should_draw_spinner();
+    when_reporting_should_write_above_progress();
+    when_verbose_should_draw_bar();
+    when_not_a_terminal_should_write_lines();
+    when_terse_multi_should_collapse_to_summary();
+    when_verbose_multi_should_draw_tasks_below_reports();
+    when_multi_tasks_sent_to_threads_should_write_lines();
+}
+
+fn when_quite_should_hide_progress() {
+    set_progress_output(ProgressOutput::Live);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        let progress = progress! { @level(Verbosity::Quite) "working" };
+
+        progress.inc(2);
+        progress.finish();
+
+        assert_eq!(2, progress.position());
+    }};
+
+    assert_eq!("", actual_stdout);
+    assert_eq!("", actual_std_err);
+}
+
+fn when_terse_should_draw_spinner() {
+    set_progress_output(ProgressOutput::Live);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        let progress = progress! { "working" };
+
+        progress.finish();
+    }};
+
+    assert_eq!("- working\r\x1b[2Kworking done\n", actual_stdout);
+    assert_eq!("", actual_std_err);
+}
+
+fn when_reporting_should_write_above_progress() {
+    set_progress_output(ProgressOutput::Live);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        let progress = progress! { "working" };
+
+        reportln! { "copied {}", "a.txt" }
+
+        progress.finish_with_message(String::from("copied"));
+    }};
+
+    assert_eq!(
+        "- working\r\x1b[2Kcopied a.txt\n- working\r\x1b[2Kcopied done\n",
+        actual_stdout
+    );
+    assert_eq!("", actual_std_err);
+}
+
+fn when_verbose_should_draw_bar() {
+    set_progress_output(ProgressOutput::Live);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        let progress = progress! { @level(Verbosity::Verbose) @total(4) "working" };
+
+        progress.inc(4);
+        progress.finish();
+    }};
+
+    assert!(actual_stdout.starts_with("working [>"), "{actual_stdout:?}");
+    assert!(actual_stdout.contains("\r\x1b[2Kworking 4/4 done in "), "{actual_stdout:?}");
+    assert_eq!("", actual_std_err);
+}
+
+fn when_not_a_terminal_should_write_lines() {
+    set_progress_output(ProgressOutput::Auto);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        let progress = progress! { @total(3usize) "copying" };
+
+        for _ in 0..3 {
+            progress.inc(1);
+        }
+
+        progress.finish();
+    }};
+
+    assert_eq!("copying 1/3\ncopying 3/3 done\n", actual_stdout);
+    assert_eq!("", actual_std_err);
+}
+
+fn when_terse_multi_should_collapse_to_summary() {
+    set_progress_output(ProgressOutput::Live);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        let downloads = progress! { @multi "downloading" };
+        let first = downloads.add(Some(1), String::from("a.txt"));
+        let second = downloads.add(None, String::from("b.txt"));
+
+        first.inc(1);
+        first.finish();
+        second.finish();
+        downloads.finish();
+    }};
+
+    assert_eq!(
+        concat!(
+            "- downloading\r\x1b[2K- downloading 0/1\r\x1b[2K- downloading 0/2",
+            "\r\x1b[2K- downloading 1/2\r\x1b[2K- downloading 2/2\r\x1b[2Kdownloading 2/2 done\n"
+        ),
+        actual_stdout
+    );
+    assert_eq!("", actual_std_err);
+}
+
+fn when_verbose_multi_should_draw_tasks_below_reports() {
+    set_progress_output(ProgressOutput::Live);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        let downloads = progress! { @level(Verbosity::Verbose) @multi "downloading" };
+        let task = downloads.add(None, String::from("a.txt"));
+
+        reportln! { "downloaded {}", "a.txt" }
+
+        task.finish();
+        downloads.finish();
+    }};
+
+    assert!(
+        actual_stdout.contains("\r\x1b[2K\x1b[1A\x1b[2Kdownloaded a.txt\n- a.txt"),
+        "{actual_stdout:?}"
+    );
+    assert!(actual_stdout.contains("\x1b[2Ka.txt done in "), "{actual_stdout:?}");
+    assert!(actual_stdout.contains("\x1b[2Kdownloading 1/1 done in "), "{actual_stdout:?}");
+    assert_eq!("", actual_std_err);
+}
+
+fn when_multi_tasks_sent_to_threads_should_write_lines() {
+    set_progress_output(ProgressOutput::Auto);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        let downloads = progress! { @level(Verbosity::Verbose) @multi "downloading" };
+
+        thread::scope(|scope| {
+            for file in ["a.txt", "b.txt"] {
+                let task = downloads.add(Some(2), file.to_string());
+
+                scope.spawn(move || {
+                    task.inc(2);
+                    task.finish();
+                });
+            }
+        });
+
+        downloads.finish();
+    }};
+
+    let last = actual_stdout.lines().last().unwrap_or_default();
+
+    assert!(actual_stdout.starts_with("downloading 0/1\n"), "{actual_stdout:?}");
+    assert!(actual_stdout.contains("a.txt 2/2 done in "), "{actual_stdout:?}");
+    assert!(actual_stdout.contains("b.txt 2/2 done in "), "{actual_stdout:?}");
+    assert!(last.starts_with("downloading 2/2 done in "), "{actual_stdout:?}");
+    assert_eq!("", actual_std_err);
+}
@@ -0,0 +1,18 @@
+use cli_toolbox::release;
+
+fn main() {
+    let _ = release! {
+        @verbose => 42,
+        @terse => 21
+    };
+
+    let _ = release! {
+        @terse => 21,
+        @terse => 42
+    };
+
+    let _ = release! {
+        @terse => 21,
+        println!("verbose message: {}", 42)
+    };
+}
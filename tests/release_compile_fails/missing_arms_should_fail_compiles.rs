@@ -0,0 +1,21 @@
+use cli_toolbox::release;
+
+fn main() {
+    let _ = release! {
+        @quiet => 0,
+        @verbose => 42
+    };
+
+    let _ = release! { @quiet => 0 };
+
+    let _ = release! {
+        @terse => 21,
+        @quiet => 0,
+        @verbose => 42
+    };
+
+    let _ = release! {
+        @terse => 21,
+        @verbose => 42
+    };
+}
@@ -55,3 +55,15 @@ fn when_quite_verbosity_should_suppress_terse_verbose() {
 
     assert_eq!(expected, actual)
 }
+
+#[test]
+fn when_quite_verbosity_should_evaluate_value() {
+    Verbosity::Quite.set_as_global();
+
+    let actual: Option<i32> = release! {
+        @terse 21
+        @verbose 42
+    };
+
+    assert_eq!(None, actual);
+}
@@ -58,3 +58,36 @@ fn when_terse_verbosity_should_evaluate_terse_suppress_verbose() {
 
     assert_eq!(expected, actual)
 }
+
+#[test]
+fn when_terse_verbosity_should_evaluate_value() {
+    Verbosity::Terse.set_as_global();
+
+    expect! { expected = Some(21), None }
+
+    let actual: Option<i32> = release! {
+        @terse 21
+        @verbose 42
+    };
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_terse_verbosity_should_not_compile_release_only_code_in_debug() {
+    Verbosity::Terse.set_as_global();
+
+    expect! { expected = 42, 0 }
+
+    let mut actual = 0;
+
+    release! { @terse actual = release_only() }
+    release! { @verbose release_only() };
+
+    assert_eq!(expected, actual)
+}
+
+#[cfg(not(debug_assertions))]
+fn release_only() -> i32 {
+    42
+}
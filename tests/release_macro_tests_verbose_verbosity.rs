@@ -59,3 +59,17 @@ fn when_verbose_verbosity_should_suppress_terse_evaluate_verbose() {
 
     assert_eq!(expected, actual)
 }
+
+#[test]
+fn when_verbose_verbosity_should_evaluate_value() {
+    Verbosity::Verbose.set_as_global();
+
+    expect! { expected = Some(42), None }
+
+    let actual: Option<i32> = release! {
+        @terse 21
+        @verbose 42
+    };
+
+    assert_eq!(expected, actual);
+}
@@ -3,61 +3,37 @@ use cli_toolbox::{report, reportln};
 fn main() {
     report! {
         @terse "terse message: {}", 42;
+        @verbose "verbose message: {}", 42;
         @terse "terse message: {}", 42
     }
 
     report! {
-        @verbose "verbose message: {}", 42;
-        @verbose "verbose message: {}", 42
+        @quiet "quiet message: {}", 42;
+        @terse "terse message: {}", 42;
+        @quiet "quiet message: {}", 42
     }
 
     report! {
         @terse "error terse message: {}", 42;
+        @verbose "verbose message: {}", 42;
         @err-terse "error terse message: {}", -42
     }
 
     report! {
-        @err-terse "error terse message: {}", -42;
-        @terse "error terse message: {}", 42
-    }
-
-    report! {
-        @err-terse "error terse message: {}", -42;
-        @err-terse "error terse message: {}", -42
-    }
-
-    report! {
-        @verbose "error verbose message: {}", 42;
-        @err-verbose "error verbose message: {}", -42
-    }
-
-    report! {
-        @err-verbose "error verbose message: {}", -42;
-        @verbose "error verbose message: {}", 42
-    }
-
-    report! {
-        @err-verbose "error verbose message: {}", -42;
-        @err-verbose "error verbose message: {}", -42
-    }
-
-    reportln! {
-        @terse "terse message: {}", 42;
-        @terse "terse message: {}", 42
-    }
-
-    reportln! {
+        @err-quiet "error quiet message: {}", -42;
         @verbose "verbose message: {}", 42;
-        @verbose "verbose message: {}", 42
+        @quiet "quiet message: {}", 42
     }
 
     reportln! {
-        @err-terse "error terse message: {}", -42;
-        @err-terse "error terse message: {}", -42
+        @always "always message: {}", 42;
+        @verbose "verbose message: {}", 42;
+        @err-always "error always message: {}", -42
     }
 
     reportln! {
+        @err-quiet "error quiet message: {}", -42;
         @err-verbose "error verbose message: {}", -42;
-        @err-verbose "error verbose message: {}", -42
+        @err-quiet "error quiet message: {}", -42
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,24 @@
+use cli_toolbox::{report, reportln};
+
+fn main() {
+    report! {
+        @always "always message: {}", 42;
+        @terse "terse message: {}", 42
+    }
+
+    report! {
+        @terse "terse message: {}", 42;
+        @err-always "error always message: {}", -42
+    }
+
+    reportln! {
+        @verbose "verbose message: {}", 42;
+        @always "always message: {}", 42
+    }
+
+    reportln! {
+        @err-always "error always message: {}", -42;
+        @verbose "verbose message: {}", 42;
+        @always "always message: {}", 42
+    }
+}
@@ -0,0 +1,25 @@
+use cli_toolbox::{report, reportln};
+
+fn main() {
+    report! {
+        @terse "terse message: {}", 42;
+        @quiet "quiet message: {}", 42
+    }
+
+    report! {
+        @quiet "quiet message: {}", 42;
+        @verbose "verbose message: {}", 42;
+        @err-quiet "error quiet message: {}", -42
+    }
+
+    reportln! {
+        @quiet "quiet message: {}", 42;
+        @terse "terse message: {}", 42;
+        @err-quiet "error quiet message: {}", -42
+    }
+
+    reportln! {
+        @always "always message: {}", 42;
+        @quiet "quiet message: {}", 42
+    }
+}
@@ -0,0 +1,29 @@
+use cli_toolbox::{report, reportln};
+
+fn main() {
+    report! {
+        @terse "terse message: {}";
+        @verbose "verbose message: {}", 42
+    }
+
+    report! {
+        @terse "terse message: {}", 42;
+        @verbose "verbose message: {2}", 42, 42
+    }
+
+    reportln! { @err-verbose "unused arguments: {}", 42, 42, answer = 42 }
+
+    reportln! { "named arguments: {answer}", answer = 42, 42 }
+
+    reportln! { "invalid spec: {:q}", 42 }
+
+    reportln! { "unmatched brace: {} }", 42 }
+
+    reportln! { "unterminated placeholder: {", 42 }
+
+    reportln! { b"byte string placeholder: {:?}", b"42" }
+
+    reportln! { b"byte string arguments: {}", b"42", b"42", answer = b"42" }
+
+    reportln! { format!("not a format string: {}", 42) }
+}
@@ -0,0 +1,7 @@
+use cli_toolbox::progress;
+
+fn main() {
+    let _ = progress! { @multi @total(3) "downloading" };
+
+    let _ = progress! { @total(3) };
+}
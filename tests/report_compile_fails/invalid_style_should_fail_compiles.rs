@@ -0,0 +1,9 @@
+use cli_toolbox::reportln;
+
+fn main() {
+    reportln! { @terse[] "terse message" }
+
+    reportln! { @terse[blink] "terse message" }
+
+    reportln! { @err-terse[plain, red] "error terse message" }
+}
@@ -0,0 +1,10 @@
+use cli_toolbox::{error, warn};
+
+fn main() {
+    error! { @err-always "error message: {}", 42 }
+
+    warn! {
+        @terse "terse warning: {}", 42;
+        @err-verbose "verbose warning: {}", 42
+    }
+}
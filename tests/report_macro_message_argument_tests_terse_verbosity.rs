@@ -316,3 +316,88 @@ fn when_message_with_unsafe_arg_should_output() {
 
     unsafe fn unsafe_method_to_call() -> usize { 42 }
 }
+
+#[test]
+fn when_message_with_captured_arg_should_output() {
+    Verbosity::Terse.set_as_global();
+
+    let expected_stdout = "terse output: 42";
+    let answer = 42;
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @terse "terse output: {answer}" }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_named_arg_should_output() {
+    Verbosity::Terse.set_as_global();
+
+    let expected_stdout = "terse output: 42";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @terse "terse output: {answer}", answer = 42 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_trailing_comma_should_output() {
+    Verbosity::Terse.set_as_global();
+
+    let expected_stdout = "terse output: 42";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @terse "terse output: {}", 42, }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_width_and_precision_args_should_output() {
+    Verbosity::Terse.set_as_global();
+
+    let expected_stdout = "terse output:   42.00";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @terse "terse output: {:>width$.precision$}", 42.0, width = 7, precision = 2 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_concat_format_should_output() {
+    Verbosity::Terse.set_as_global();
+
+    let expected_stdout = "terse output: 42";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @terse concat!("terse ", "output: {}"), 42 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_prebuilt_args_should_output() {
+    Verbosity::Terse.set_as_global();
+
+    let expected_stdout = "terse output: 42";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @terse args: format_args!("terse output: {}", 42) }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
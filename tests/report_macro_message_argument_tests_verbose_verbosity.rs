@@ -1,7 +1,10 @@
+use std::ffi::OsStr;
+use std::path::Path;
+
 use test_toolbox::capture;
 use verbosity::Verbosity;
 
-use cli_toolbox::report;
+use cli_toolbox::{report, reportln};
 
 const EXPECTED_BLANK_STD_ERR: &str = "";
 
@@ -316,3 +319,115 @@ fn when_message_with_unsafe_arg_should_output() {
 
     unsafe fn unsafe_method_to_call() -> usize { 42 }
 }
+
+#[test]
+fn when_message_with_captured_arg_should_output() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected_stdout = "verbose output: 42";
+    let answer = 42;
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @verbose "verbose output: {answer}" }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_named_arg_should_output() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected_stdout = "verbose output: 42";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @verbose "verbose output: {answer}", answer = 42 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_trailing_comma_should_output() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected_stdout = "verbose output: 42";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @verbose "verbose output: {}", 42, }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_width_and_precision_args_should_output() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected_stdout = "verbose output:   42.00";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @verbose "verbose output: {:>width$.precision$}", 42.0, width = 7, precision = 2 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_concat_format_should_output() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected_stdout = "verbose output: 42";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @verbose concat!("verbose ", "output: {}"), 42 }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_message_with_prebuilt_args_should_output() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected_stdout = "verbose output: 42";
+
+    let (actual_stdout, actual_stderr) = capture! {
+        report! { @verbose args: format_args!("verbose output: {}", 42) }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_stderr);
+}
+
+#[test]
+fn when_byte_string_message_with_byte_args_should_write_raw_bytes() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected = b"verbose output: \xff\x00 {42} name.txt dir/name.txt".to_vec();
+    let mut actual = Vec::new();
+
+    report! {
+        @verbose @to(&mut actual)
+        b"verbose output: {} {{42}} {} {}", [0xff_u8, 0x00].as_slice(), OsStr::new("name.txt"), Path::new("dir/name.txt")
+    }
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn when_byte_string_message_with_line_break_should_write_raw_bytes() {
+    Verbosity::Verbose.set_as_global();
+
+    let expected = b"verbose output: \xff\n".to_vec();
+    let mut actual = Vec::new();
+
+    reportln! { @verbose @to(&mut actual) b"verbose output: {}", [0xff_u8] }
+
+    assert_eq!(expected, actual);
+}
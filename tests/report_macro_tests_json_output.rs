@@ -0,0 +1,125 @@
+use cli_toolbox_runtime::{OutputFormat, set_output_format};
+use test_toolbox::capture;
+use verbosity::Verbosity;
+
+use cli_toolbox::{report, report_block, reportln, try_reportln};
+
+const EXPECTED_BLANK_STD_ERR: &str = "";
+const EXPECTED_BLANK_STD_OUT: &str = "";
+
+// every test sets the same output format and verbosity
+fn set_json_output() {
+    Verbosity::Verbose.set_as_global();
+
+    set_output_format(OutputFormat::Json);
+}
+
+#[test]
+fn when_json_output_should_report_json_lines() {
+    set_json_output();
+
+    let expected = concat!(
+        r#"{"version":1,"level":"terse","stream":"stdout","message":"terse 42","module":"report_macro_tests_json_output"}"#,
+        "\n"
+    );
+
+    let (actual_stdout, actual_std_err) = capture! {
+        report! { "terse {}", 42 }
+    };
+
+    assert_eq!(expected, actual_stdout, "report!");
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err, "report!");
+
+    let expected = concat!(
+        r#"{"version":1,"level":"verbose","stream":"stderr","message":"verbose","module":"report_macro_tests_json_output"}"#,
+        "\n"
+    );
+
+    let (actual_stdout, actual_std_err) = capture! {
+        reportln! { @err-verbose[red] "verbose" }
+    };
+
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout, "reportln!");
+    assert_eq!(expected, actual_std_err, "reportln!");
+}
+
+#[test]
+fn when_json_output_should_escape_message() {
+    set_json_output();
+
+    let expected = concat!(
+        r#"{"version":1,"level":"always","stream":"stdout","message":"\"quoted\" \\ \ttab\nline\u0007","module":"report_macro_tests_json_output"}"#,
+        "\n"
+    );
+
+    let (actual_stdout, actual_std_err) = capture! {
+        reportln! { @always "\"quoted\" \\ \ttab\nline\x07" }
+    };
+
+    assert_eq!(expected, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
+
+#[test]
+fn when_json_output_should_report_json_lines_to_writer() {
+    set_json_output();
+
+    let expected = concat!(
+        r#"{"version":1,"level":"verbose","stream":"writer","message":"verbose","module":"report_macro_tests_json_output"}"#,
+        "\n"
+    );
+
+    let mut buffer = Vec::new();
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        try_reportln! {
+            @quiet "quiet";
+            @terse "terse";
+            @verbose @to(&mut buffer) "verbose"
+        }.unwrap();
+    }};
+
+    assert_eq!(expected, String::from_utf8(buffer).unwrap());
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
+
+#[test]
+fn when_json_output_should_report_byte_string_message_lossily() {
+    set_json_output();
+
+    let expected = concat!(
+        r#"{"version":1,"level":"verbose","stream":"writer","message":"raw "#,
+        '\u{fffd}',
+        r#" bytes","module":"report_macro_tests_json_output"}"#,
+        "\n"
+    );
+
+    let mut buffer = Vec::new();
+
+    try_reportln! { @verbose @to(&mut buffer) b"raw {} bytes", [0xff_u8] }.unwrap();
+
+    assert_eq!(expected, String::from_utf8(buffer).unwrap());
+}
+
+#[test]
+fn when_json_output_should_report_block_json_lines() {
+    set_json_output();
+
+    let expected = concat!(
+        r#"{"version":1,"level":"terse","stream":"stdout","message":"line one","module":"report_macro_tests_json_output"}"#,
+        "\n",
+        r#"{"version":1,"level":"verbose","stream":"stdout","message":"line two","module":"report_macro_tests_json_output"}"#,
+        "\n"
+    );
+
+    let (actual_stdout, actual_std_err) = capture! {
+        report_block! {
+            "line one";
+            @verbose "line two"
+        }
+    };
+
+    assert_eq!(expected, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
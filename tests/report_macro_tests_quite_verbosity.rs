@@ -1,7 +1,7 @@
 use test_toolbox::capture;
 use verbosity::Verbosity;
 
-use cli_toolbox::{report, reportln};
+use cli_toolbox::{report, report_block, reportln, try_report, try_reportln};
 
 const EXPECTED_BLANK_STD_ERR: &str = "";
 const EXPECTED_BLANK_STD_OUT: &str = "";
@@ -197,3 +197,106 @@ fn when_quite_verbosity_should_suppress_verbose() {
     assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout, "reportln!");
     assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err, "reportln!");
 }
+
+#[test]
+fn when_quite_verbosity_should_suppress_writer() {
+    Verbosity::Quite.set_as_global();
+
+    assert_eq!(Verbosity::Quite, Verbosity::level());
+
+    let mut actual = Vec::new();
+
+    report! { @to(&mut actual) "default message" }
+    reportln! { @terse @to(&mut actual) "terse message" }
+    reportln! { @verbose @to(&mut actual) "verbose message" }
+    reportln! {
+        @terse @to(&mut actual) "terse message";
+        @verbose @to(&mut actual) "verbose message"
+    }
+
+    assert!(actual.is_empty());
+}
+
+#[test]
+fn when_quite_verbosity_should_try_report_nothing() {
+    Verbosity::Quite.set_as_global();
+
+    assert_eq!(Verbosity::Quite, Verbosity::level());
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        try_report! { @err-terse "error terse message" }.expect("try_report!");
+        try_reportln! { @verbose "verbose message" }.expect("try_reportln!");
+        try_reportln! {
+            @terse "terse message";
+            @err-verbose "error verbose message"
+        }.expect("try_reportln!");
+    }};
+
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+}
+
+#[test]
+fn when_quite_verbosity_should_suppress_report_block() {
+    Verbosity::Quite.set_as_global();
+
+    assert_eq!(Verbosity::Quite, Verbosity::level());
+
+    let (actual_stdout, actual_std_err) = capture! {
+        report_block! {
+            @terse "terse message";
+            @verbose "verbose message";
+            "default message";
+            @err-terse "error terse message";
+            @err-verbose "error verbose message"
+        }
+    };
+
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout, "report_block!");
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err, "report_block!");
+}
+
+#[test]
+fn when_quite_verbosity_should_report_always() {
+    Verbosity::Quite.set_as_global();
+
+    assert_eq!(Verbosity::Quite, Verbosity::level());
+
+    let expected_stdout = "always message\n";
+    let expected_std_err = "error always message\nerror always message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! { @always "always message" }
+        reportln! { @err-always "error always message" }
+        reportln! {
+            @err-always "error always message";
+            @err-verbose "error verbose message"
+        }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "reportln!");
+    assert_eq!(expected_std_err, actual_std_err, "reportln!");
+}
+
+#[test]
+fn when_quite_verbosity_should_report_quiet_only_when_quite() {
+    Verbosity::Quite.set_as_global();
+
+    assert_eq!(Verbosity::Quite, Verbosity::level());
+
+    let expected_stdout = "quiet message\nquiet message\n";
+    let expected_std_err = "error quiet message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! { @quiet "quiet message" }
+        reportln! { @err-quiet "error quiet message" }
+        reportln! {
+            @quiet "quiet message";
+            @terse "terse message";
+            @verbose "verbose message"
+        }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "reportln!");
+    assert_eq!(expected_std_err, actual_std_err, "reportln!");
+}
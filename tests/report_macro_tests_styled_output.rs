@@ -0,0 +1,95 @@
+//! runs without the test harness, so the color choice is set by each test without other tests
+//! changing it in parallel, the color choice also overrides the color environment variables
+
+use cli_toolbox_runtime::{ColorChoice, set_color_choice};
+use test_toolbox::capture;
+use verbosity::Verbosity;
+
+use cli_toolbox::reportln;
+
+fn main() {
+    Verbosity::Terse.set_as_global();
+
+    when_color_always_should_style_messages();
+    when_color_never_should_not_style_messages();
+    when_color_auto_should_not_style_writers();
+    when_not_styled_should_not_style_messages();
+    when_default_should_use_default_style_of_stream();
+    when_plain_should_not_style_messages();
+}
+
+fn when_color_always_should_style_messages() {
+    set_color_choice(ColorChoice::Always);
+
+    let mut actual = Vec::new();
+
+    reportln! { @terse[green, bold] @to(&mut actual) "terse message" }
+    reportln! { @to(&mut actual) "writer message" }
+
+    assert_eq!("\x1b[32;1mterse message\x1b[0m\nwriter message\n", String::from_utf8_lossy(&actual));
+}
+
+fn when_color_never_should_not_style_messages() {
+    set_color_choice(ColorChoice::Never);
+
+    let mut actual = Vec::new();
+
+    reportln! { @terse[green, bold] @to(&mut actual) "terse message" }
+
+    let (actual_stdout, actual_std_err) = capture! {
+        reportln! { @err-terse "error terse message" }
+    };
+
+    assert_eq!("terse message\n", String::from_utf8_lossy(&actual));
+    assert_eq!("", actual_stdout);
+    assert_eq!("error terse message\n", actual_std_err);
+}
+
+fn when_color_auto_should_not_style_writers() {
+    set_color_choice(ColorChoice::Auto);
+
+    let mut actual = Vec::new();
+
+    reportln! { @terse[green, bold] @to(&mut actual) "terse message" }
+
+    assert_eq!("terse message\n", String::from_utf8_lossy(&actual));
+}
+
+fn when_not_styled_should_not_style_messages() {
+    set_color_choice(ColorChoice::Always);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! { "terse message" }
+        reportln! { @err-terse "error terse message" }
+    }};
+
+    assert_eq!("terse message\n", actual_stdout);
+    assert_eq!("error terse message\n", actual_std_err);
+}
+
+fn when_default_should_use_default_style_of_stream() {
+    set_color_choice(ColorChoice::Always);
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! { @terse[default] "default message" }
+        reportln! { @err-terse[default] "error default message" }
+        reportln! { @err-terse[default, underline] "error underlined message" }
+    }};
+
+    assert_eq!("default message\n", actual_stdout);
+    assert_eq!(
+        "\x1b[1;31merror default message\x1b[0m\n\x1b[1;31;4merror underlined message\x1b[0m\n",
+        actual_std_err
+    );
+}
+
+fn when_plain_should_not_style_messages() {
+    set_color_choice(ColorChoice::Always);
+
+    let (actual_stdout, actual_std_err) = capture! {
+        reportln! { @err-terse[plain] "error terse message" }
+    };
+
+    assert_eq!("", actual_stdout);
+    assert_eq!("error terse message\n", actual_std_err);
+}
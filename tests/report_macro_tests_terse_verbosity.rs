@@ -1,7 +1,7 @@
 use test_toolbox::capture;
 use verbosity::Verbosity;
 
-use cli_toolbox::{report, reportln};
+use cli_toolbox::{report, report_block, reportln, try_report, try_reportln};
 
 const EXPECTED_BLANK_STD_ERR: &str = "";
 const EXPECTED_BLANK_STD_OUT: &str = "";
@@ -222,3 +222,182 @@ fn when_terse_verbosity_should_suppress_verbose() {
     assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err, "reportln!");
 }
 
+
+#[test]
+fn when_terse_verbosity_should_write_terse_to_writer_not_verbose() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let mut actual = Vec::new();
+
+    report! { @to(&mut actual) "default message; " }
+    reportln! { @terse @to(&mut actual) "terse message" }
+    reportln! { @verbose @to(&mut actual) "verbose message" }
+    reportln! {
+        @terse @to(&mut actual) "terse message";
+        @verbose @to(&mut actual) "verbose message"
+    }
+
+    let (actual_stdout, actual_std_err) = capture! {
+        reportln! {
+            @terse @to(&mut actual) "terse message";
+            @err-verbose "error verbose message"
+        }
+    };
+
+    assert_eq!(
+        "default message; terse message\nterse message\nterse message\n",
+        String::from_utf8_lossy(&actual)
+    );
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
+
+#[test]
+fn when_terse_verbosity_should_try_report_terse_not_verbose() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected = "terse message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        try_reportln! { @verbose "verbose message" }.expect("try_reportln!");
+        try_reportln! {
+            @terse "terse message";
+            @err-verbose "error verbose message"
+        }.expect("try_reportln!");
+    }};
+
+    assert_eq!(expected, actual_stdout, "try_reportln!");
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err, "try_reportln!");
+
+    let expected = "error terse message";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        try_report! { @err-terse "error terse message" }.expect("try_report!");
+    }};
+
+    assert_eq!(expected, actual_std_err, "try_report!");
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout, "try_report!");
+}
+
+#[test]
+fn when_terse_verbosity_should_report_block_terse_lines() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected_stdout = "terse message\ndefault message\n";
+    let expected_std_err = "error terse message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {
+        report_block! {
+            @terse "terse message";
+            @verbose "verbose message";
+            "default message";
+            @err-terse "error terse message";
+            @err-verbose "error verbose message"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout, "report_block!");
+    assert_eq!(expected_std_err, actual_std_err, "report_block!");
+}
+
+#[test]
+fn when_terse_verbosity_should_report_always() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected_stdout = "always message\n";
+    let expected_std_err = "error always message\nerror always message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! { @always "always message" }
+        reportln! { @err-always "error always message" }
+        reportln! {
+            @err-always "error always message";
+            @err-verbose "error verbose message"
+        }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "reportln!");
+    assert_eq!(expected_std_err, actual_std_err, "reportln!");
+}
+
+#[test]
+fn when_terse_verbosity_should_report_quiet_only_when_quite() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected_stdout = "terse message\n";
+    let expected_std_err = EXPECTED_BLANK_STD_ERR;
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! { @quiet "quiet message" }
+        reportln! { @err-quiet "error quiet message" }
+        reportln! {
+            @quiet "quiet message";
+            @terse "terse message";
+            @verbose "verbose message"
+        }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "reportln!");
+    assert_eq!(expected_std_err, actual_std_err, "reportln!");
+}
+
+#[test]
+fn when_terse_verbosity_should_report_grouped_terse_clauses() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected_stdout = "terse summary\nterse message\n";
+    let expected_std_err = "error terse message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! {
+            @terse "terse summary";
+            @err-terse "error terse message"
+        }
+        reportln! {
+            @quiet "quiet message";
+            @terse "terse message";
+            @verbose "verbose message";
+            @err-verbose "error verbose message"
+        }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "reportln!");
+    assert_eq!(expected_std_err, actual_std_err, "reportln!");
+}
+
+#[test]
+fn when_terse_verbosity_should_write_grouped_terse_clauses_in_order() {
+    Verbosity::Terse.set_as_global();
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let mut actual = Vec::new();
+
+    reportln! {
+        @terse @to(&mut actual) "first terse message";
+        @terse @to(&mut actual) "second terse message";
+        @verbose @to(&mut actual) "verbose message"
+    }
+
+    try_reportln! {
+        @terse @to(&mut actual) "third terse message";
+        @terse @to(&mut actual) "fourth terse message"
+    }.expect("try_reportln!");
+
+    assert_eq!(
+        "first terse message\nsecond terse message\nthird terse message\nfourth terse message\n",
+        String::from_utf8_lossy(&actual)
+    );
+}
@@ -1,7 +1,7 @@
 use test_toolbox::capture;
 use verbosity::Verbosity;
 
-use cli_toolbox::{report, reportln};
+use cli_toolbox::{report, report_block, reportln, try_report, try_reportln};
 
 const EXPECTED_BLANK_STD_ERR: &str = "";
 const EXPECTED_BLANK_STD_OUT: &str = "";
@@ -230,3 +230,154 @@ fn when_verbose_verbosity_should_evaluate_verbose() {
     assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err, "reportln!");
 }
 
+
+#[test]
+fn when_verbose_verbosity_should_write_verbose_to_writer() {
+    Verbosity::Verbose.set_as_global();
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let mut actual = Vec::new();
+
+    report! { @to(&mut actual) "default message; " }
+    reportln! { @terse @to(&mut actual) "terse message" }
+    reportln! { @verbose @to(&mut actual) "verbose message" }
+    reportln! {
+        @terse @to(&mut actual) "terse message";
+        @verbose @to(&mut actual) "verbose message"
+    }
+
+    let (actual_stdout, actual_std_err) = capture! {
+        reportln! {
+            @err-terse "error terse message";
+            @verbose @to(&mut actual) "verbose message"
+        }
+    };
+
+    assert_eq!(
+        "default message; terse message\nverbose message\nverbose message\nverbose message\n",
+        String::from_utf8_lossy(&actual)
+    );
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
+
+#[test]
+fn when_verbose_verbosity_should_try_report_verbose() {
+    Verbosity::Verbose.set_as_global();
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let expected = "terse message\nverbose message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        try_reportln! { @terse "terse message" }.expect("try_reportln!");
+        try_reportln! {
+            @err-terse "error terse message";
+            @verbose "verbose message"
+        }.expect("try_reportln!");
+    }};
+
+    assert_eq!(expected, actual_stdout, "try_reportln!");
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err, "try_reportln!");
+
+    let expected = "error verbose message";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        try_report! { @err-verbose "error verbose message" }.expect("try_report!");
+    }};
+
+    assert_eq!(expected, actual_std_err, "try_report!");
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout, "try_report!");
+}
+
+#[test]
+fn when_verbose_verbosity_should_report_block_terse_and_verbose_lines() {
+    Verbosity::Verbose.set_as_global();
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let expected_stdout = "terse message\nverbose message\ndefault message\n";
+    let expected_std_err = "error terse message\nerror verbose message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {
+        report_block! {
+            @terse "terse message";
+            @verbose "verbose message";
+            "default message";
+            @err-terse "error terse message";
+            @err-verbose "error verbose message"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout, "report_block!");
+    assert_eq!(expected_std_err, actual_std_err, "report_block!");
+}
+
+#[test]
+fn when_verbose_verbosity_should_report_always() {
+    Verbosity::Verbose.set_as_global();
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let expected_stdout = "always message\n";
+    let expected_std_err = "error always message\nerror always message\nerror verbose message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! { @always "always message" }
+        reportln! { @err-always "error always message" }
+        reportln! {
+            @err-always "error always message";
+            @err-verbose "error verbose message"
+        }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "reportln!");
+    assert_eq!(expected_std_err, actual_std_err, "reportln!");
+}
+
+#[test]
+fn when_verbose_verbosity_should_report_quiet_only_when_quite() {
+    Verbosity::Verbose.set_as_global();
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let expected_stdout = "verbose message\n";
+    let expected_std_err = EXPECTED_BLANK_STD_ERR;
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        reportln! { @quiet "quiet message" }
+        reportln! { @err-quiet "error quiet message" }
+        reportln! {
+            @quiet "quiet message";
+            @terse "terse message";
+            @verbose "verbose message"
+        }
+    }};
+
+    assert_eq!(expected_stdout, actual_stdout, "reportln!");
+    assert_eq!(expected_std_err, actual_std_err, "reportln!");
+}
+
+#[test]
+fn when_verbose_verbosity_should_report_grouped_verbose_clauses() {
+    Verbosity::Verbose.set_as_global();
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let expected_stdout = "always message\nfirst verbose message\nsecond verbose message\n";
+    let expected_std_err = "error always message\nerror verbose message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {
+        reportln! {
+            @always "always message";
+            @err-always "error always message";
+            @verbose "first verbose message";
+            @err-verbose "error verbose message";
+            @verbose "second verbose message"
+        }
+    };
+
+    assert_eq!(expected_stdout, actual_stdout, "reportln!");
+    assert_eq!(expected_std_err, actual_std_err, "reportln!");
+}
@@ -0,0 +1,63 @@
+use cli_toolbox_runtime::{ColorChoice, set_color_choice};
+use test_toolbox::capture;
+use verbosity::Verbosity;
+
+use cli_toolbox::{error, info, note, success, warn};
+
+const EXPECTED_BLANK_STD_ERR: &str = "";
+const EXPECTED_BLANK_STD_OUT: &str = "";
+
+#[test]
+fn when_quite_verbosity_should_report_error() {
+    Verbosity::Quite.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Quite, Verbosity::level());
+
+    let expected = "error: failed 42 times\n";
+
+    let (actual_stdout, actual_std_err) = capture! {
+        error! { "failed {} times", 42 }
+    };
+
+    assert_eq!(expected, actual_std_err);
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+}
+
+#[test]
+fn when_quite_verbosity_should_suppress_error_verbose() {
+    Verbosity::Quite.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Quite, Verbosity::level());
+
+    let expected = "error: failed\n";
+
+    let (actual_stdout, actual_std_err) = capture! {
+        error! {
+            "failed";
+            @verbose "failed to open config"
+        }
+    };
+
+    assert_eq!(expected, actual_std_err);
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+}
+
+#[test]
+fn when_quite_verbosity_should_suppress_warn_info_success_note() {
+    Verbosity::Quite.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Quite, Verbosity::level());
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        warn! { "warning message" }
+        info! { "info message" }
+        success! { "success message" }
+        note! { @terse "note message" }
+    }};
+
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+}
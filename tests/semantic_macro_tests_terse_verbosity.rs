@@ -0,0 +1,66 @@
+use cli_toolbox_runtime::{ColorChoice, set_color_choice};
+use test_toolbox::capture;
+use verbosity::Verbosity;
+
+use cli_toolbox::{error, info, note, success, warn};
+
+const EXPECTED_BLANK_STD_ERR: &str = "";
+const EXPECTED_BLANK_STD_OUT: &str = "";
+
+#[test]
+fn when_terse_verbosity_should_report_error_warn_to_std_err() {
+    Verbosity::Terse.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected = "error: failed 42 times\nwarning: terse warning\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        error! { "failed {} times", 42 }
+        warn! {
+            @terse "terse warning";
+            @verbose "verbose warning"
+        }
+    }};
+
+    assert_eq!(expected, actual_std_err);
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+}
+
+#[test]
+fn when_terse_verbosity_should_report_info_success_to_std_out() {
+    Verbosity::Terse.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected = "info: info message\nsuccess: success message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        info! { "info message" }
+        success! { "success message" }
+    }};
+
+    assert_eq!(expected, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
+
+#[test]
+fn when_terse_verbosity_should_suppress_default_note() {
+    Verbosity::Terse.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Terse, Verbosity::level());
+
+    let expected = "note: terse note\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        note! { "verbose note" }
+        note! { @terse "terse note" }
+    }};
+
+    assert_eq!(expected, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
+
@@ -0,0 +1,101 @@
+use std::sync::Mutex;
+
+use cli_toolbox_runtime::{ColorChoice, error_count, set_color_choice, warning_count};
+use test_toolbox::capture;
+use verbosity::Verbosity;
+
+use cli_toolbox::{error, info, note, success, warn};
+
+const EXPECTED_BLANK_STD_ERR: &str = "";
+const EXPECTED_BLANK_STD_OUT: &str = "";
+
+// tests that report errors or warnings hold this lock, so the counts are not changed in parallel
+static COUNTED: Mutex<()> = Mutex::new(());
+
+#[test]
+fn when_verbose_verbosity_should_report_error_warn_to_std_err() {
+    let _counted = COUNTED.lock().unwrap_or_else(|err| err.into_inner());
+
+    Verbosity::Verbose.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let expected = "error: failed 42 times\nwarning: verbose warning\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        error! { "failed {} times", 42 }
+        warn! {
+            @terse "terse warning";
+            @verbose "verbose warning"
+        }
+    }};
+
+    assert_eq!(expected, actual_std_err);
+    assert_eq!(EXPECTED_BLANK_STD_OUT, actual_stdout);
+}
+
+#[test]
+fn when_verbose_verbosity_should_report_info_success_to_std_out() {
+    Verbosity::Verbose.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let expected = "info: info message\nsuccess: verbose success message\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        info! { "info message" }
+        success! {
+            @terse "terse success message";
+            @verbose "verbose success message"
+        }
+    }};
+
+    assert_eq!(expected, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
+
+#[test]
+fn when_verbose_verbosity_should_report_default_note() {
+    Verbosity::Verbose.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let expected = "note: verbose note\nnote: terse note\n";
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        note! { "verbose note" }
+        note! { @terse "terse note" }
+    }};
+
+    assert_eq!(expected, actual_stdout);
+    assert_eq!(EXPECTED_BLANK_STD_ERR, actual_std_err);
+}
+
+#[test]
+fn when_verbose_verbosity_should_count_errors_and_warnings() {
+    let _counted = COUNTED.lock().unwrap_or_else(|err| err.into_inner());
+
+    Verbosity::Verbose.set_as_global();
+    set_color_choice(ColorChoice::Never);
+
+    assert_eq!(Verbosity::Verbose, Verbosity::level());
+
+    let errors = error_count();
+    let warnings = warning_count();
+
+    let (actual_stdout, actual_std_err) = capture! {{
+        error! { "first error" }
+        error! { "second error" }
+        warn! { "reported warning" }
+        warn! { @level(Verbosity::Quite) "suppressed warning" }
+        info! { "not counted" }
+    }};
+
+    assert_eq!(2, error_count() - errors);
+    assert_eq!(2, warning_count() - warnings);
+    assert_eq!("info: not counted\n", actual_stdout);
+    assert_eq!("error: first error\nerror: second error\nwarning: reported warning\n", actual_std_err);
+}